@@ -0,0 +1,300 @@
+use crate::builder::prelude::{ Ident, Strand, Tokenizer, IndexType };
+use crate::builder::index::Index;
+use crate::error::Error;
+use surrealdb::sql::statements::{ DefineAnalyzerStatement, DefineStatement };
+use surrealdb::sql::Statement;
+use std::fmt;
+use std::str::FromStr;
+
+/// The language of the `SNOWBALL` stemming filter.
+#[derive(Debug, Clone)]
+pub enum Language {
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Language::Arabic => "ARABIC",
+            Language::Danish => "DANISH",
+            Language::Dutch => "DUTCH",
+            Language::English => "ENGLISH",
+            Language::French => "FRENCH",
+            Language::German => "GERMAN",
+            Language::Greek => "GREEK",
+            Language::Hungarian => "HUNGARIAN",
+            Language::Italian => "ITALIAN",
+            Language::Norwegian => "NORWEGIAN",
+            Language::Portuguese => "PORTUGUESE",
+            Language::Romanian => "ROMANIAN",
+            Language::Russian => "RUSSIAN",
+            Language::Spanish => "SPANISH",
+            Language::Swedish => "SWEDISH",
+            Language::Tamil => "TAMIL",
+            Language::Turkish => "TURKISH",
+        })
+    }
+}
+
+impl FromStr for Language {
+    type Err = Error;
+    fn from_str(value: &str) -> Result<Self,Error> {
+        [
+            Language::Arabic, Language::Danish, Language::Dutch, Language::English,
+            Language::French, Language::German, Language::Greek, Language::Hungarian,
+            Language::Italian, Language::Norwegian, Language::Portuguese, Language::Romanian,
+            Language::Russian, Language::Spanish, Language::Swedish, Language::Tamil,
+            Language::Turkish,
+        ].into_iter()
+            .find(|language| language.to_string().eq_ignore_ascii_case(value.trim()))
+            .ok_or_else(|| Error::identifier("language",value,"Expecting a snowball language, e.g. english."))
+    }
+}
+
+/// The filters applied to the tokens of an analyzer.
+/// See: <https://surrealdb.com/docs/surrealdb/surrealql/statements/define/analyzer#filters>
+#[derive(Debug, Clone)]
+pub enum Filter {
+    Ascii,
+    Lowercase,
+    Uppercase,
+    EdgeNgram(u16, u16),
+    Ngram(u16, u16),
+    Snowball(Language),
+}
+
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::Ascii => f.write_str("ASCII"),
+            Filter::Lowercase => f.write_str("LOWERCASE"),
+            Filter::Uppercase => f.write_str("UPPERCASE"),
+            Filter::EdgeNgram(min, max) => write!(f, "EDGENGRAM({},{})", min, max),
+            Filter::Ngram(min, max) => write!(f, "NGRAM({},{})", min, max),
+            Filter::Snowball(language) => write!(f, "SNOWBALL({})", language),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = Error;
+    /// Parses a filter as rendered by a `DEFINE ANALYZER` statement, e.g. `EDGENGRAM(1,3)`.
+    fn from_str(value: &str) -> Result<Self,Error> {
+        let invalid = || Error::identifier("analyzer filter",value,"Expecting ASCII, LOWERCASE, UPPERCASE, EDGENGRAM(min,max), NGRAM(min,max) or SNOWBALL(language).");
+        let (name, args) = match value.trim().split_once('(') {
+            Some((name, args)) => (name, args.strip_suffix(')').ok_or_else(invalid)?),
+            None => (value.trim(), ""),
+        };
+        let range = || -> Result<(u16,u16),Error> {
+            let (min, max) = args.split_once(',').ok_or_else(invalid)?;
+            Ok((min.trim().parse().map_err(|_| invalid())?, max.trim().parse().map_err(|_| invalid())?))
+        };
+        match name.to_ascii_uppercase().as_str() {
+            "ASCII" => Ok(Filter::Ascii),
+            "LOWERCASE" => Ok(Filter::Lowercase),
+            "UPPERCASE" => Ok(Filter::Uppercase),
+            "EDGENGRAM" => range().map(|(min, max)| Filter::EdgeNgram(min, max)),
+            "NGRAM" => range().map(|(min, max)| Filter::Ngram(min, max)),
+            "SNOWBALL" => args.parse().map(Filter::Snowball),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// # Analyzer
+///
+/// * `name` - The analyzer name.
+/// * `function` - The name of a `fn::` function applied to the text before it is tokenized.
+/// * `tokenizers` - The tokenizers which split the text. See: <https://surrealdb.com/docs/surrealdb/surrealql/statements/define/analyzer#tokenizers>
+/// * `filters` - The filters applied to the tokens. See: `Filter`.
+/// * `comment` - Comment on the analyzer.
+/// * `if_not_exists` - Create or define the analyzer if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Analyzer<'a>{
+    pub name: &'a str,
+    pub function: &'a str,
+    pub tokenizers: Vec<Tokenizer>,
+    pub filters: Vec<Filter>,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+}
+impl <'a> Analyzer<'a> {
+    /// # Build a DEFINE ANALYZER Statement
+    /// ## Parameter
+    /// * `item` - The `Analyzer` struct.
+    pub fn build(
+        item: Analyzer
+    ) -> Result<String,Error> {
+        // Analyzer ====================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Analyzer", field: "name" });
+        }
+        let mut stmt: DefineAnalyzerStatement = DefineAnalyzerStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.if_not_exists  = item.if_not_exists;
+        let function: &str = item.function.strip_prefix("fn::").unwrap_or(item.function);
+        if !function.is_empty() {
+            stmt.function = Some(Ident::from(function.to_string()));
+        }
+        if !item.tokenizers.is_empty() {
+            stmt.tokenizers = Some(item.tokenizers);
+        }
+        if !item.filters.is_empty() {
+            // The filter types of the sql module are private, so they are
+            // obtained by parsing the typed filters.
+            let filters: Vec<String> = item.filters.iter().map(|filter| filter.to_string()).collect();
+            let parsed = surrealdb::sql::parse(
+                format!("DEFINE ANALYZER {} FILTERS {}",stmt.name,filters.join(",")).as_str()
+            ).map_err(|error| Error::invalid("Analyzer",format!("Invalid filters {}: {}",filters.join(","),error)))?;
+            match parsed.0.0.into_iter().next() {
+                Some(Statement::Define(DefineStatement::Analyzer(define))) => {
+                    stmt.filters = define.filters;
+                }
+                _ => return Err(Error::invalid("Analyzer",format!("Invalid filters {}.",filters.join(","))))
+            }
+        }
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+
+    /// # Validate the analyzers of search indexes
+    /// Every `IndexType::Search` index must name one of the given analyzers, SurrealDB defines such an index and only fails on the next write.
+    /// Checked by `Query::build_with_indexes` and `Schema::statements`, built statements are checked by `Analyzer::check`.
+    /// ## Parameters
+    /// * `analyzers` - The analyzers defined in the batch.
+    /// * `indexes` - The indexes defined in the batch.
+    pub fn validate(
+        analyzers: &[Analyzer],
+        indexes: &[Index]
+    ) -> Result<(),Error> {
+        for index in indexes {
+            if let IndexType::Search(params) = &index.index {
+                if !analyzers.iter().any(|analyzer| analyzer.name == params.az.0) {
+                    return Err(Error::UnknownAnalyzer(params.az.0.clone()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// # Check the analyzers of built search indexes
+    /// Every search index of the statements must name an analyzer defined before it.
+    /// Checked by `Query::build` and `Db::execute`, which also accepts the analyzers of the database.
+    /// `Transaction::build` does not check, a `Transaction` item of a `Query` is checked.
+    /// ## Parameters
+    /// * `stmts` - The parsed statements of an item.
+    /// * `analyzers` - The analyzers defined before the statements, the analyzers they define are added.
+    pub fn check(
+        stmts: &[Statement],
+        analyzers: &mut Vec<String>
+    ) -> Result<(),Error> {
+        for stmt in stmts {
+            match stmt {
+                Statement::Define(DefineStatement::Analyzer(define)) => analyzers.push(define.name.0.clone()),
+                Statement::Define(DefineStatement::Index(define)) => if let IndexType::Search(params) = &define.index {
+                    if !analyzers.contains(&params.az.0) {
+                        return Err(Error::UnknownAnalyzer(params.az.0.clone()));
+                    }
+                },
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::prelude::*;
+    use surrealdb::sql::Statement;
+
+    #[test]
+    fn test_builder(){
+        let analyzers: Vec<Analyzer> = Vec::from([
+            Analyzer {
+                name: "english",
+                tokenizers: vec![Tokenizer::Blank,Tokenizer::Class],
+                filters: vec![Filter::Lowercase,Filter::Ascii,Filter::Snowball(Language::English)],
+                comment: "english text",
+                if_not_exists: true,
+                ..Analyzer::default()
+            },
+            Analyzer {
+                name: "autocomplete",
+                tokenizers: vec![Tokenizer::Camel,Tokenizer::Punct],
+                filters: vec![Filter::Uppercase,Filter::EdgeNgram(2,10)],
+                ..Analyzer::default()
+            }
+        ]);
+        let search: IndexType = match surrealdb::sql::parse("DEFINE INDEX body_search ON post FIELDS body SEARCH ANALYZER english BM25").unwrap().0.0.remove(0) {
+            Statement::Define(DefineStatement::Index(define)) => define.index,
+            _ => panic!("Expecting an index")
+        };
+        let indexes: Vec<Index> = Vec::from([
+            Index {
+                name: "body_search",
+                table: "post",
+                cols: vec![Idiom::from("body")],
+                index: search,
+                ..Index::default()
+            }
+        ]);
+        let stmts = Query::new(analyzers).build_with_indexes(indexes.clone()).unwrap();
+        assert!(stmts.contains("TOKENIZERS BLANK,CLASS FILTERS LOWERCASE,ASCII,SNOWBALL(ENGLISH)"));
+        assert!(stmts.contains("FILTERS UPPERCASE,EDGENGRAM(2,10)"));
+        assert!(stmts.contains("SEARCH ANALYZER english"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Analyzer::build(Analyzer::default()).is_err());
+        assert!(Query::new(vec![Analyzer { name: "simple", ..Analyzer::default() }]).build_with_indexes(indexes).is_err());
+    }
+
+    #[test]
+    fn test_check(){
+        use crate::builder::statement::Statement as Item;
+        use crate::builder::transaction::Transaction;
+        let search: Index = Index {
+            name: "body_search",
+            table: "post",
+            cols: vec![Idiom::from("body")],
+            index: match surrealdb::sql::parse("DEFINE INDEX body_search ON post FIELDS body SEARCH ANALYZER english BM25").unwrap().0.0.remove(0) {
+                Statement::Define(DefineStatement::Index(define)) => define.index,
+                _ => panic!("Expecting an index")
+            },
+            ..Index::default()
+        };
+        let english: Analyzer = Analyzer { name: "english", tokenizers: vec![Tokenizer::Blank], ..Analyzer::default() };
+        let items: Vec<Box<dyn Item>> = vec![Box::new(english.clone()),Box::new(search.clone())];
+        assert!(Query::new(items).build().is_ok());
+        // Expecting Error
+        let items: Vec<Box<dyn Item>> = vec![Box::new(search.clone()),Box::new(english.clone())];
+        assert_eq!(Query::new(items).build().unwrap_err(),Error::Query { index: 0, error: Box::new(Error::UnknownAnalyzer("english".to_string())) });
+        let items: Vec<Box<dyn Item>> = vec![
+            Box::new(Analyzer { name: "simple", tokenizers: vec![Tokenizer::Blank], ..Analyzer::default() }),
+            Box::new(Transaction { stmts: vec![Index::build(search.clone()).unwrap()], ..Transaction::default() }),
+        ];
+        assert_eq!(Query::new(items).build().unwrap_err().index(),Some(1));
+    }
+
+}
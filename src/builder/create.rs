@@ -0,0 +1,148 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr::{ self, Assignment, Return };
+use crate::error::Error;
+use surrealdb::sql::statements::CreateStatement;
+use surrealdb::sql::Data;
+/// # Create
+///
+/// * `what` - The tables or record ids to create. See: `expr::table`, `expr::thing`.
+/// * `only` - Return a single record instead of an array.
+/// * `content` - The content of the record. See: `expr::content` to serialize a `serde::Serialize` value.
+/// * `set` - The fields of the record. Only one of `content` or `set` can be set.
+/// * `output` - The `RETURN` clause.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Create the records in parallel.
+#[derive(Debug, Clone, Default)]
+pub struct Create<'a>{
+    pub what: Vec<Value>,
+    pub only: bool,
+    pub content: Option<Value>,
+    pub set: Vec<Assignment<'a>>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+}
+impl <'a> Create<'a> {
+    /// # Build a CREATE Statement
+    /// ## Parameter
+    /// * `item` - The `Create` struct.
+    pub fn build(
+        item: Create
+    ) -> Result<String,Error> {
+        // Create ======================================================
+        if item.what.is_empty() {
+            return Err(Error::invalid("Create","Create requires at least one table or record id."));
+        }
+        if item.content.is_some() && !item.set.is_empty() {
+            return Err(Error::invalid("Create","Create cannot use both CONTENT and SET."));
+        }
+        let mut stmt: CreateStatement = CreateStatement::default();
+        stmt.what       = expr::values(item.what);
+        stmt.only       = item.only;
+        stmt.parallel   = item.parallel;
+        if let Some(content) = item.content {
+            stmt.data = Some(Data::ContentExpression(content));
+        }
+        if !item.set.is_empty() {
+            stmt.data = Some(Data::SetExpression(expr::assignments(item.set)?));
+        }
+        if let Some(output) = item.output {
+            stmt.output = Some(expr::output(output)?);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::db::Db;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    struct Person {
+        name: String,
+        age: i64,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_builder(){
+        let stmt: String = Create::build(Create {
+            what: vec![expr::thing("person:tobie").unwrap()],
+            only: true,
+            content: Some(expr::content(Person {
+                name: "Tobie".to_string(),
+                age: 33,
+                tags: vec!["founder".to_string()],
+            }).unwrap()),
+            output: Some(Return::Fields(vec!["name","age"])),
+            ..Create::default()
+        }).unwrap();
+        assert_eq!(stmt,"CREATE ONLY person:tobie CONTENT { age: 33, name: 'Tobie', tags: ['founder'] } RETURN name, age");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        let stmt: String = Create::build(Create {
+            what: vec![expr::table("person")],
+            set: vec![
+                Assignment::Set("name",Value::from("Jaime")),
+                Assignment::Set("settings.active",Value::from(true)),
+            ],
+            output: Some(Return::None),
+            timeout: Some(core::time::Duration::from_millis(500)),
+            parallel: true,
+            ..Create::default()
+        }).unwrap();
+        assert_eq!(stmt,"CREATE person SET name = 'Jaime', settings.active = true RETURN NONE TIMEOUT 500ms PARALLEL");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        // Expecting Error
+        assert!(Create::build(Create::default()).is_err());
+        assert!(Create::build(Create {
+            what: vec![expr::table("person")],
+            content: Some(Value::from(1)),
+            set: vec![Assignment::Set("name",Value::from("Jaime"))],
+            ..Create::default()
+        }).is_err());
+        assert!(Create::build(Create {
+            what: vec![expr::table("person")],
+            output: Some(Return::Fields(vec![])),
+            ..Create::default()
+        }).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        let creates: Vec<Create> = Vec::from([
+            Create {
+                what: vec![expr::thing("person:tobie").unwrap()],
+                only: true,
+                content: Some(expr::content(Person {
+                    name: "Tobie".to_string(),
+                    age: 33,
+                    tags: vec!["founder".to_string()],
+                }).unwrap()),
+                output: Some(Return::Fields(vec!["name","age"])),
+                ..Create::default()
+            },
+            Create {
+                what: vec![expr::table("person")],
+                set: vec![
+                    Assignment::Set("name",Value::from("Jaime")),
+                    Assignment::Set("settings.active",Value::from(true)),
+                ],
+                output: Some(Return::None),
+                ..Create::default()
+            }
+        ]);
+        let report = db.execute(&Query::new(creates)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let names: Vec<String> = db.query("SELECT VALUE name FROM person ORDER BY name").await.unwrap().take(0).unwrap();
+        assert_eq!(names,vec!["Jaime","Tobie"]);
+    }
+
+}
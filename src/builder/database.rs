@@ -0,0 +1,70 @@
+use crate::builder::prelude::{ Ident, Strand, ChangeFeed };
+use crate::error::Error;
+use surrealdb::sql::statements::DefineDatabaseStatement;
+/// # Database
+///
+/// * `name` - The database name.
+/// * `comment` - Comment on the database.
+/// * `changefeed` - The duration in seconds to keep the historical changes of every table in the database. `0` disables the changefeed.
+/// * `if_not_exists` - Create or define the database if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Database<'a>{
+    pub name: &'a str,
+    pub comment: &'a str,
+    pub changefeed: u64,
+    pub if_not_exists: bool,
+}
+impl <'a> Database<'a> {
+    /// # Build a DEFINE DATABASE Statement
+    /// ## Parameter
+    /// * `item` - The `Database` struct.
+    pub fn build(
+        item: Database
+    ) -> Result<String,Error> {
+        // Database ====================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Database", field: "name" });
+        }
+        let mut stmt: DefineDatabaseStatement = DefineDatabaseStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        if item.changefeed > 0 {
+            let mut changefeed = ChangeFeed::default();
+            changefeed.expiry = core::time::Duration::from_secs(item.changefeed);
+            stmt.changefeed = Some(changefeed);
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let databases: Vec<Database> = Vec::from([
+            Database {
+                name: "app",
+                comment: "Application data",
+                changefeed: 3600,
+                if_not_exists: true,
+            },
+            Database {
+                name: "analytics",
+                ..Database::default()
+            }
+        ]);
+        let stmts: String = Query::new(databases).build().unwrap();
+        assert_eq!(stmts,"DEFINE DATABASE IF NOT EXISTS app COMMENT 'Application data' CHANGEFEED 1h;DEFINE DATABASE analytics;");
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Database::build(Database::default()).is_err());
+    }
+
+}
@@ -0,0 +1,147 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr::{ self, Return };
+use crate::error::Error;
+use surrealdb::sql::statements::DeleteStatement;
+/// # Delete
+///
+/// * `what` - The tables, record ids, record ranges or edges to delete. See: `expr::table`, `expr::thing`, `expr::range`, `expr::edges`.
+/// * `only` - Return a single record instead of an array.
+/// * `cond` - The `WHERE` condition. See: `expr::value`.
+/// * `output` - The `RETURN` clause, e.g. `Return::Before` or `Return::None`.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Delete the records in parallel.
+/// * `unbounded` - Confirm a delete of more than single records. Building a delete of any target other than a record id, e.g. a table, a range or edges, without `cond` fails unless `true`.
+#[derive(Debug, Clone, Default)]
+pub struct Delete<'a>{
+    pub what: Vec<Value>,
+    pub only: bool,
+    pub cond: Option<Value>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+    pub unbounded: bool,
+}
+impl <'a> Delete<'a> {
+    /// # Build a DELETE Statement
+    /// ## Parameter
+    /// * `item` - The `Delete` struct.
+    pub fn build(
+        item: Delete
+    ) -> Result<String,Error> {
+        // Delete ======================================================
+        if item.what.is_empty() {
+            return Err(Error::invalid("Delete","Delete requires at least one table, record id, range or edge."));
+        }
+        if item.cond.is_none() && !item.unbounded {
+            if let Some(what) = item.what.iter().find(|what| !matches!(what, Value::Thing(_))) {
+                return Err(Error::invalid("Delete",format!("Delete of {} without WHERE requires `unbounded` to be confirmed.",what)));
+            }
+        }
+        let mut stmt: DeleteStatement = DeleteStatement::default();
+        stmt.what       = expr::values(item.what);
+        stmt.only       = item.only;
+        stmt.parallel   = item.parallel;
+        if let Some(value) = item.cond {
+            stmt.cond = Some(expr::cond(value));
+        }
+        if let Some(output) = item.output {
+            stmt.output = Some(expr::output(output)?);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::db::Db;
+
+    #[test]
+    fn test_builder(){
+        let deletes: Vec<Delete> = Vec::from([
+            Delete {
+                what: vec![expr::table("session")],
+                cond: Some(expr::value("expires_at < time::now()").unwrap()),
+                output: Some(Return::None),
+                timeout: Some(core::time::Duration::from_secs(30)),
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::thing("person:tobie").unwrap()],
+                only: true,
+                output: Some(Return::Before),
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::range("log",Some(Value::from(1)),Some(Value::from(1000))).unwrap()],
+                unbounded: true,
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::edges("person:tobie","likes").unwrap()],
+                cond: Some(expr::value("at < time::now() - 7d").unwrap()),
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::table("temp")],
+                unbounded: true,
+                parallel: true,
+                ..Delete::default()
+            }
+        ]);
+        let stmts: String = Query::new(deletes).build().unwrap();
+        assert!(stmts.contains("DELETE session WHERE expires_at < time::now() RETURN NONE TIMEOUT 30s;"));
+        assert!(stmts.contains("DELETE ONLY person:tobie RETURN BEFORE;"));
+        assert!(stmts.contains("DELETE log:1..1000;"));
+        assert!(stmts.contains("DELETE person:tobie->likes WHERE at < time::now() - 1w;"));
+        assert!(stmts.contains("DELETE temp PARALLEL;"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Delete::build(Delete::default()).is_err());
+        assert!(Delete::build(Delete { what: vec![expr::table("person")], ..Delete::default() }).is_err());
+        assert!(Delete::build(Delete { what: vec![Value::from("person")], ..Delete::default() }).is_err());
+        assert!(Delete::build(Delete { what: vec![expr::value("person").unwrap()], ..Delete::default() }).is_err());
+        assert!(Delete::build(Delete { what: vec![expr::range("log",Some(Value::from(1)),Some(Value::from(1000))).unwrap()], ..Delete::default() }).is_err());
+        assert!(Delete::build(Delete { what: vec![expr::edges("person:tobie","likes").unwrap()], ..Delete::default() }).is_err());
+        assert!(Delete::build(Delete { what: vec![expr::thing("person:tobie").unwrap(), expr::table("person")], ..Delete::default() }).is_err());
+        assert!(expr::edges("person","likes").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        db.query("CREATE session:1 SET expires_at = time::now() - 1h; CREATE session:2 SET expires_at = time::now() + 1h; CREATE person:tobie; CREATE log:1; CREATE log:2; CREATE temp:1").await.unwrap();
+        let deletes: Vec<Delete> = Vec::from([
+            Delete {
+                what: vec![expr::table("session")],
+                cond: Some(expr::value("expires_at < time::now()").unwrap()),
+                output: Some(Return::None),
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::thing("person:tobie").unwrap()],
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::range("log",Some(Value::from(1)),Some(Value::from(1000))).unwrap()],
+                unbounded: true,
+                ..Delete::default()
+            },
+            Delete {
+                what: vec![expr::table("temp")],
+                unbounded: true,
+                ..Delete::default()
+            }
+        ]);
+        let report = db.execute(&Query::new(deletes)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let remaining: Vec<String> = db.query("SELECT VALUE meta::tb(id) + ':' + <string> meta::id(id) FROM session, person, log, temp").await.unwrap().take(0).unwrap();
+        assert_eq!(remaining,vec!["session:2"]);
+    }
+
+}
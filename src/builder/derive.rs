@@ -0,0 +1,306 @@
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::index::Index;
+use crate::builder::statement::Statement;
+use crate::error::Error;
+
+/// The table definition of a record struct, implemented by `#[derive(SurrealTable)]` of the `derive` feature.
+///
+/// The table name is the struct name in snake case. The `id` field is not defined, SurrealDB defines it.
+/// Rust types are mapped to SurrealDB types: `String` to `string`, integers to `int`, floats to `float`,
+/// `bool` to `bool`, `Option<T>` to `option<T>`, `Vec<T>` to `array<T>`, `HashSet<T>` to `set<T>`,
+/// `HashMap` and `BTreeMap` to `object`, `Thing` to `record`, `Value` to `any` and the SurrealDB `Datetime`, `Number`
+/// and `Bytes` to their SurrealDB type. The chrono `DateTime<Utc>`, `NaiveDateTime` and `NaiveDate` are mapped to `datetime`,
+/// chrono serializes them as strings so they require `#[serde(with = "surrealqb::builder::derive::datetime")]`.
+/// Any other type, e.g. a nested struct or a `Duration`, fails to compile unless it sets `#[surreal(kind = "..")]`.
+///
+/// The field names follow `#[serde(rename = "..")]` and `#[serde(rename_all = "..")]`, fields with `#[serde(skip)]`
+/// or `#[serde(skip_serializing)]` are not defined.
+///
+/// ## Struct Attributes
+/// * `#[surreal(table = "name")]` - The table name.
+/// * `#[surreal(schemaless)]` - Define a SCHEMALESS table.
+/// * `#[surreal(comment = "..")]` - Comment on the table.
+/// * `#[surreal(permissions = "..")]` - The permissions of the table. See: `expr::permissions`.
+///
+/// ## Field Attributes
+/// * `#[surreal(rename = "name")]` - The field name, fails to compile when it differs from `#[serde(rename = "..")]`.
+/// * `#[surreal(skip)]` - Do not define the field.
+/// * `#[surreal(kind = "..")]` - The type of the field instead of the mapped type. See: `expr::kind`.
+/// * `#[surreal(record = "table")]` - The table of a `Thing`, can be repeated.
+/// * `#[surreal(assert = "..")]`, `#[surreal(default = "..")]`, `#[surreal(value = "..")]` - The expressions of the field. See: `expr::value`.
+/// * `#[surreal(readonly)]`, `#[surreal(flexible)]` - Define a READONLY or FLEXIBLE field.
+/// * `#[surreal(permissions = "..")]` - The permissions of the field.
+/// * `#[surreal(comment = "..")]` - Comment on the field.
+/// * `#[surreal(index)]`, `#[surreal(unique)]` - Index the field as `<field>_idx`. Fields with the same `#[surreal(index = "name")]` or `#[surreal(unique = "name")]` share a composite index.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize, SurrealTable)]
+/// #[surreal(comment = "The people")]
+/// struct Person {
+///     id: Option<Thing>,
+///     #[surreal(unique, assert = "string::is::email($value)")]
+///     email: String,
+///     #[surreal(record = "company")]
+///     employer: Option<Thing>,
+/// }
+/// let stmts: String = Query::new(Person::statements()?).build()?;
+/// ```
+pub trait SurrealTable {
+    /// The `Table` definition.
+    fn table() -> Result<Table<'static>,Error>;
+
+    /// The `Field` definitions in order of the struct fields.
+    fn fields() -> Result<Vec<Field<'static>>,Error>;
+
+    /// The `Index` definitions in order of their first field.
+    fn indexes() -> Result<Vec<Index<'static>>,Error>;
+
+    /// The table, the fields and the indexes in order, ready for `Query::new`.
+    fn statements() -> Result<Vec<Box<dyn Statement>>,Error> {
+        let mut stmts: Vec<Box<dyn Statement>> = vec![Box::new(Self::table()?)];
+        for field in Self::fields()? {
+            stmts.push(Box::new(field));
+        }
+        for index in Self::indexes()? {
+            stmts.push(Box::new(index));
+        }
+        Ok(stmts)
+    }
+}
+
+/// Serialize a chrono `DateTime<Utc>`, `NaiveDateTime` or `NaiveDate` as a SurrealDB `datetime` in UTC.
+///
+/// ```ignore
+/// #[serde(with = "surrealqb::builder::derive::datetime")]
+/// created_at: DateTime<Utc>,
+/// #[serde(with = "surrealqb::builder::derive::datetime::option", default)]
+/// deleted_at: Option<NaiveDate>,
+/// ```
+pub mod datetime {
+    use chrono::{ DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc };
+    use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+    use surrealdb::sql::Datetime;
+
+    /// A chrono date stored as a datetime in UTC.
+    pub trait Chrono: Sized {
+        fn to_utc(&self) -> DateTime<Utc>;
+        fn from_utc(datetime: DateTime<Utc>) -> Self;
+    }
+
+    impl Chrono for DateTime<Utc> {
+        fn to_utc(&self) -> DateTime<Utc> {
+            *self
+        }
+
+        fn from_utc(datetime: DateTime<Utc>) -> Self {
+            datetime
+        }
+    }
+
+    impl Chrono for NaiveDateTime {
+        fn to_utc(&self) -> DateTime<Utc> {
+            self.and_utc()
+        }
+
+        fn from_utc(datetime: DateTime<Utc>) -> Self {
+            datetime.naive_utc()
+        }
+    }
+
+    impl Chrono for NaiveDate {
+        fn to_utc(&self) -> DateTime<Utc> {
+            self.and_time(NaiveTime::MIN).and_utc()
+        }
+
+        fn from_utc(datetime: DateTime<Utc>) -> Self {
+            datetime.date_naive()
+        }
+    }
+
+    pub fn serialize<T: Chrono, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok,S::Error> {
+        Datetime::from(value.to_utc()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T: Chrono, D: Deserializer<'de>>(deserializer: D) -> Result<T,D::Error> {
+        Datetime::deserialize(deserializer).map(|datetime| T::from_utc(datetime.0))
+    }
+
+    /// Serialize an optional chrono date as an `option<datetime>`.
+    pub mod option {
+        use super::Chrono;
+        use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+        use surrealdb::sql::Datetime;
+
+        pub fn serialize<T: Chrono, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok,S::Error> {
+            value.as_ref().map(|value| Datetime::from(value.to_utc())).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, T: Chrono, D: Deserializer<'de>>(deserializer: D) -> Result<Option<T>,D::Error> {
+            Option::<Datetime>::deserialize(deserializer).map(|datetime| datetime.map(|datetime| T::from_utc(datetime.0)))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::create::Create;
+    use crate::builder::expr;
+    use crate::db::Db;
+    use serde::{ Deserialize, Serialize };
+    use std::collections::{ BTreeMap, HashSet };
+    use surrealdb::sql::{ Datetime, Thing };
+    use chrono::{ DateTime, NaiveDate, NaiveDateTime, Utc };
+    use surrealqb_derive::SurrealTable;
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, SurrealTable)]
+    #[surreal(comment = "The people", permissions = "FOR select FULL FOR create, update, delete WHERE id = $auth.id")]
+    struct PersonRecord {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<Thing>,
+        #[surreal(unique, assert = "string::is::email($value)")]
+        email: String,
+        #[surreal(index = "name_idx")]
+        first_name: String,
+        #[surreal(index = "name_idx")]
+        #[serde(rename = "last")]
+        last_name: Option<String>,
+        #[surreal(default = "0", readonly)]
+        age: u8,
+        score: f64,
+        tags: Vec<String>,
+        roles: HashSet<String>,
+        #[surreal(record = "company")]
+        employer: Option<Thing>,
+        friends: Vec<Thing>,
+        #[surreal(flexible, kind = "object")]
+        address: Address,
+        #[surreal(flexible, kind = "object")]
+        settings: BTreeMap<String, String>,
+        #[surreal(default = "time::now()", comment = "Created at")]
+        created_at: Datetime,
+        #[serde(skip)]
+        password: String,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize, SurrealTable)]
+    #[serde(rename_all = "camelCase")]
+    struct LoginEvent {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        id: Option<Thing>,
+        user_name: String,
+        #[serde(with = "datetime")]
+        logged_in_at: DateTime<Utc>,
+        #[serde(with = "datetime::option", default)]
+        logged_out_at: Option<NaiveDateTime>,
+        #[serde(rename = "day", with = "datetime")]
+        login_day: NaiveDate,
+        #[surreal(skip)]
+        #[serde(default)]
+        session: String,
+    }
+
+    #[tokio::test]
+    async fn test_derive(){
+        let stmts: String = Query::new(PersonRecord::statements().unwrap()).build().unwrap();
+        assert!(stmts.starts_with("DEFINE TABLE person_record TYPE NORMAL SCHEMAFULL COMMENT 'The people' PERMISSIONS FOR select FULL, FOR create, update, delete WHERE id = $auth.id;"));
+        for stmt in [
+            "DEFINE FIELD email ON person_record TYPE string ASSERT string::is::email($value) PERMISSIONS FULL;",
+            "DEFINE FIELD first_name ON person_record TYPE string PERMISSIONS FULL;",
+            "DEFINE FIELD last ON person_record TYPE option<string> PERMISSIONS FULL;",
+            "DEFINE FIELD age ON person_record TYPE int DEFAULT 0 READONLY PERMISSIONS FULL;",
+            "DEFINE FIELD score ON person_record TYPE float PERMISSIONS FULL;",
+            "DEFINE FIELD tags ON person_record TYPE array<string> PERMISSIONS FULL;",
+            "DEFINE FIELD roles ON person_record TYPE set<string> PERMISSIONS FULL;",
+            "DEFINE FIELD employer ON person_record TYPE option<record<company>> PERMISSIONS FULL;",
+            "DEFINE FIELD friends ON person_record TYPE array<record> PERMISSIONS FULL;",
+            "DEFINE FIELD address ON person_record FLEXIBLE TYPE object PERMISSIONS FULL;",
+            "DEFINE FIELD settings ON person_record FLEXIBLE TYPE object PERMISSIONS FULL;",
+            "DEFINE FIELD created_at ON person_record TYPE datetime DEFAULT time::now() COMMENT 'Created at' PERMISSIONS FULL;",
+            "DEFINE INDEX email_idx ON person_record FIELDS email UNIQUE;",
+            "DEFINE INDEX name_idx ON person_record FIELDS first_name, last;",
+        ] {
+            assert!(stmts.contains(stmt),"Missing {} in {}",stmt,stmts);
+        }
+        assert!(!stmts.contains("FIELD id ") && !stmts.contains("password"));
+        let db = Db::memory("test","test").await.unwrap();
+        let report = db.execute(&Query::new(PersonRecord::statements().unwrap())).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        // A record of the struct is accepted by its own schema.
+        let person: PersonRecord = PersonRecord {
+            id: None,
+            email: "tobie@surrealdb.com".to_string(),
+            first_name: "Tobie".to_string(),
+            last_name: Some("Morgan Hitchcock".to_string()),
+            age: 33,
+            score: 9.5,
+            tags: vec!["founder".to_string()],
+            roles: HashSet::from(["admin".to_string()]),
+            employer: Some(Thing::from(("company","surrealdb"))),
+            friends: vec![Thing::from(("person_record","jaime"))],
+            address: Address { city: "London".to_string() },
+            settings: BTreeMap::from([("theme".to_string(),"dark".to_string())]),
+            created_at: Datetime::default(),
+            password: "secret".to_string(),
+        };
+        let report = db.execute(&Query::new(vec![Create {
+            what: vec![expr::thing("person_record:tobie").unwrap()],
+            content: Some(expr::content(&person).unwrap()),
+            ..Create::default()
+        }])).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let created: Option<PersonRecord> = db.query("SELECT * FROM ONLY person_record:tobie").await.unwrap().take(0).unwrap();
+        let created: PersonRecord = created.unwrap();
+        assert_eq!(created.id,Some(Thing::from(("person_record","tobie"))));
+        assert_eq!(created.last_name,person.last_name);
+        assert_eq!(created.address.city,"London");
+        assert_eq!(created.settings,person.settings);
+        assert!(created.password.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_derive_serde(){
+        let stmts: String = Query::new(LoginEvent::statements().unwrap()).build().unwrap();
+        for stmt in [
+            "DEFINE FIELD userName ON login_event TYPE string PERMISSIONS FULL;",
+            "DEFINE FIELD loggedInAt ON login_event TYPE datetime PERMISSIONS FULL;",
+            "DEFINE FIELD loggedOutAt ON login_event TYPE option<datetime> PERMISSIONS FULL;",
+            "DEFINE FIELD day ON login_event TYPE datetime PERMISSIONS FULL;",
+        ] {
+            assert!(stmts.contains(stmt),"Missing {} in {}",stmt,stmts);
+        }
+        assert!(!stmts.contains("session"));
+        let db = Db::memory("test","test").await.unwrap();
+        let report = db.execute(&Query::new(LoginEvent::statements().unwrap())).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        // The chrono dates are stored as datetimes.
+        let logged_in_at: DateTime<Utc> = DateTime::parse_from_rfc3339("2024-07-01T09:30:00Z").unwrap().with_timezone(&Utc);
+        let event: LoginEvent = LoginEvent {
+            id: Some(Thing::from(("login_event","tobie"))),
+            user_name: "tobie".to_string(),
+            logged_in_at,
+            logged_out_at: Some(logged_in_at.naive_utc()),
+            login_day: logged_in_at.date_naive(),
+            session: String::new(),
+        };
+        let report = db.execute(&Query::new(vec![Create {
+            what: vec![expr::thing("login_event:tobie").unwrap()],
+            content: Some(expr::content(&event).unwrap()),
+            ..Create::default()
+        }])).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let days: Vec<bool> = db.query("SELECT VALUE type::is::datetime(day) AND day = d'2024-07-01T00:00:00Z' FROM login_event").await.unwrap().take(0).unwrap();
+        assert_eq!(days,vec![true]);
+        let created: Option<LoginEvent> = db.query("SELECT * FROM ONLY login_event:tobie").await.unwrap().take(0).unwrap();
+        assert_eq!(created,Some(event));
+    }
+
+}
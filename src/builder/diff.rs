@@ -0,0 +1,370 @@
+use crate::builder::prelude::IndexType;
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::index::Index;
+use crate::builder::event::Event;
+use crate::builder::param::Param;
+use crate::builder::function::Function;
+use crate::builder::analyzer::Analyzer;
+use crate::builder::remove::{ Remove, RemoveKind };
+use crate::builder::schema::Schema;
+use crate::builder::statement::Statement;
+use crate::error::Error;
+
+/// The impact of a change on the deployed schema and its data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeKind {
+    /// Defines a schema object which does not exist yet.
+    Additive,
+    /// Redefines or removes a schema object without touching the records, e.g. a comment, a default, an event or a plain index.
+    Modifying,
+    /// Drops records or values, or changes a constraint, a computed value or a permission which guards them.
+    Destructive,
+}
+
+/// # Change
+///
+/// * `kind` - The impact of the change which is value of enum `ChangeKind`.
+/// * `statement` - The DEFINE or REMOVE statement of the change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub statement: String,
+}
+
+/// # Diff
+/// The ordered changes which move the deployed schema to the desired schema, e.g. an `Introspection::schema` to the schema of the builders.
+/// Build them with `Query::new(diff.changes)` or run them with `Db::execute`.
+///
+/// * `changes` - The REMOVE statements, dependents before their dependencies, then the DEFINE statements in dependency order.
+#[derive(Debug, Clone, Default)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    /// # Compare two Schemas
+    /// Schema objects are matched by name, fields, indexes and events by name within their table.
+    /// A schema object is redefined when its DEFINE statement differs, `if_not_exists` is ignored.
+    /// A change is destructive when it drops records or values, or changes a constraint, a computed value or a permission which guards them:
+    /// * Tables - removing it, turning on `drop`, changing its view, SCHEMAFULL, kind, relation or permissions.
+    /// * Fields - removing it, changing its type, `flexible`, `readonly`, assertion, VALUE or permissions.
+    /// * Indexes - removing a UNIQUE index, or turning an index UNIQUE or back.
+    ///
+    /// Any other change is modifying, e.g. a comment, a default, an event or a plain index.
+    /// ## Parameters
+    /// * `from` - The deployed schema.
+    /// * `to` - The desired schema.
+    pub fn between<'a>(
+        from: Schema<'a>,
+        to: Schema<'a>
+    ) -> Result<Diff,Error> {
+        // Diff ========================================================
+        let from: Schema<'a> = Schema::resolve(from)?;
+        let to: Schema<'a> = Schema::resolve(to)?;
+        let mut diff: Diff = Diff::default();
+
+        // REMOVE, the dependents before their dependencies.
+        for current in from.tables.iter() {
+            let Some(target) = to.tables.iter().find(|target| target.table.name == current.table.name) else {
+                continue;
+            };
+            for event in current.events.iter().filter(|event| !target.events.iter().any(|target| target.name == event.name)) {
+                diff.remove(ChangeKind::Modifying, event.name, RemoveKind::Event(event.table))?;
+            }
+            for index in current.indexes.iter().filter(|index| !target.indexes.iter().any(|target| target.name == index.name)) {
+                let kind: ChangeKind = match index.index {
+                    IndexType::Uniq => ChangeKind::Destructive,
+                    _ => ChangeKind::Modifying,
+                };
+                diff.remove(kind, index.name, RemoveKind::Index(index.table))?;
+            }
+            for field in current.fields.iter().filter(|field| !target.fields.iter().any(|target| target.name == field.name)) {
+                diff.remove(ChangeKind::Destructive, field.name, RemoveKind::Field(field.table))?;
+            }
+        }
+        for current in from.tables.iter().rev().filter(|current| !to.tables.iter().any(|target| target.table.name == current.table.name)) {
+            diff.remove(ChangeKind::Destructive, current.table.name, RemoveKind::Table)?;
+        }
+        for analyzer in from.analyzers.iter().filter(|analyzer| !to.analyzers.iter().any(|target| target.name == analyzer.name)) {
+            diff.remove(ChangeKind::Modifying, analyzer.name, RemoveKind::Analyzer)?;
+        }
+        for function in from.functions.iter().filter(|function| !to.functions.iter().any(|target| Diff::same_function(target, function))) {
+            diff.remove(ChangeKind::Modifying, function.name, RemoveKind::Function)?;
+        }
+        for param in from.params.iter().filter(|param| !to.params.iter().any(|target| target.name == param.name)) {
+            diff.remove(ChangeKind::Modifying, param.name, RemoveKind::Param)?;
+        }
+
+        // DEFINE, in the order of `Schema::statements`.
+        for target in to.params.iter() {
+            let current = from.params.iter().find(|current| current.name == target.name);
+            diff.define(
+                current.map(|current| Param { if_not_exists: false, ..current.clone() }),
+                Param { if_not_exists: false, ..target.clone() },
+                ChangeKind::Modifying
+            )?;
+        }
+        for target in to.functions.iter() {
+            let current = from.functions.iter().find(|current| Diff::same_function(current, target));
+            diff.define(
+                current.map(|current| Function { if_not_exists: false, ..current.clone() }),
+                Function { if_not_exists: false, ..target.clone() },
+                ChangeKind::Modifying
+            )?;
+        }
+        for target in to.analyzers.iter() {
+            let current = from.analyzers.iter().find(|current| current.name == target.name);
+            diff.define(
+                current.map(|current| Analyzer { if_not_exists: false, ..current.clone() }),
+                Analyzer { if_not_exists: false, ..target.clone() },
+                ChangeKind::Modifying
+            )?;
+        }
+        for target in to.tables.iter().map(|target| &target.table) {
+            let current = from.tables.iter().map(|current| &current.table).find(|current| current.name == target.name);
+            let kind: ChangeKind = match current {
+                Some(current) if (target.drop && !current.drop)
+                    || target.view != current.view
+                    || target.schema_less != current.schema_less
+                    || target.kind != current.kind
+                    || (target.relation_in, target.relation_out) != (current.relation_in, current.relation_out)
+                    || target.permissions != current.permissions => ChangeKind::Destructive,
+                _ => ChangeKind::Modifying,
+            };
+            diff.define(
+                current.map(|current| Table { if_not_exists: false, ..current.clone() }),
+                Table { if_not_exists: false, ..target.clone() },
+                kind
+            )?;
+        }
+        for target in to.tables.iter() {
+            let current = from.tables.iter().find(|current| current.table.name == target.table.name);
+            for field in target.fields.iter() {
+                let current = current.and_then(|current| current.fields.iter().find(|current| current.name == field.name));
+                let kind: ChangeKind = match current {
+                    Some(current) if current.kind != field.kind
+                        || current.flexible != field.flexible
+                        || current.readonly != field.readonly
+                        || current.assert != field.assert
+                        || current.value != field.value
+                        || current.permissions != field.permissions => ChangeKind::Destructive,
+                    _ => ChangeKind::Modifying,
+                };
+                diff.define(
+                    current.map(|current| Field { if_not_exists: false, ..current.clone() }),
+                    Field { if_not_exists: false, ..field.clone() },
+                    kind
+                )?;
+            }
+            for index in target.indexes.iter() {
+                let current = current.and_then(|current| current.indexes.iter().find(|current| current.name == index.name));
+                let kind: ChangeKind = match current {
+                    Some(current) if (index.index == IndexType::Uniq) != (current.index == IndexType::Uniq) => ChangeKind::Destructive,
+                    _ => ChangeKind::Modifying,
+                };
+                diff.define(
+                    current.map(|current| Index { if_not_exists: false, ..current.clone() }),
+                    Index { if_not_exists: false, ..index.clone() },
+                    kind
+                )?;
+            }
+            for event in target.events.iter() {
+                let current = current.and_then(|current| current.events.iter().find(|current| current.name == event.name));
+                diff.define(
+                    current.map(|current| Event { if_not_exists: false, ..current.clone() }),
+                    Event { if_not_exists: false, ..event.clone() },
+                    ChangeKind::Modifying
+                )?;
+            }
+        }
+        Ok(diff)
+    }
+
+    /// `true` when the schemas are the same.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The changes which drop records or values, or change what guards them.
+    pub fn destructive(&self) -> impl Iterator<Item = &Change> {
+        self.changes.iter().filter(|change| change.kind == ChangeKind::Destructive)
+    }
+
+    /// # Refuse Destructive Changes
+    /// Fails with the first destructive change, e.g. to block data loss in CI.
+    pub fn check(&self) -> Result<(),Error> {
+        match self.destructive().next() {
+            Some(change) => Err(Error::invalid("Diff",format!("Destructive change: {}",change.statement))),
+            None => Ok(()),
+        }
+    }
+
+    fn same_function(function: &Function, other: &Function) -> bool {
+        function.name.strip_prefix("fn::").unwrap_or(function.name) == other.name.strip_prefix("fn::").unwrap_or(other.name)
+    }
+
+    fn remove(&mut self, kind: ChangeKind, name: &str, remove: RemoveKind) -> Result<(),Error> {
+        self.changes.push(Change {
+            kind,
+            statement: Remove::build(Remove { name, kind: remove, if_exists: false })?,
+        });
+        Ok(())
+    }
+
+    /// Define the target when it is new, redefine it as `kind` when it differs from the current definition.
+    fn define(&mut self, current: Option<impl Statement>, target: impl Statement, kind: ChangeKind) -> Result<(),Error> {
+        let statement: String = target.statement()?;
+        match current {
+            None => self.changes.push(Change { kind: ChangeKind::Additive, statement }),
+            Some(current) if current.statement()? != statement => self.changes.push(Change { kind, statement }),
+            Some(_) => {}
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::prelude::*;
+    use crate::builder::schema::TableSchema;
+    use crate::builder::query::Query;
+    use crate::builder::expr;
+    use crate::db::Db;
+    use surrealdb::sql::Statement;
+    use surrealdb::sql::statements::DefineStatement;
+
+    #[tokio::test]
+    async fn test_diff(){
+        let from: Schema = Schema {
+            params: vec![Param { name: "max_likes", value: Value::from(100), ..Param::default() }],
+            tables: vec![
+                TableSchema {
+                    table: Table { name: "person", kind: TableKind::Normal, ..Table::default() },
+                    fields: vec![
+                        Field { name: "name", kind: Kind::String, ..Field::default() },
+                        Field { name: "age", kind: Kind::Int, ..Field::default() },
+                    ],
+                    indexes: vec![Index { name: "age_idx", cols: vec![Idiom::from("age")], ..Index::default() }],
+                    ..TableSchema::default()
+                },
+                TableSchema {
+                    table: Table { name: "article", kind: TableKind::Normal, ..Table::default() },
+                    fields: vec![Field { name: "body", kind: Kind::String, ..Field::default() }],
+                    ..TableSchema::default()
+                },
+            ],
+            ..Schema::default()
+        };
+        let to: Schema = Schema {
+            params: vec![Param { name: "max_likes", value: Value::from(100), if_not_exists: true, ..Param::default() }],
+            tables: vec![
+                TableSchema {
+                    table: Table { name: "person", kind: TableKind::Normal, ..Table::default() },
+                    fields: vec![
+                        Field { name: "name", kind: Kind::String, comment: "Full name", ..Field::default() },
+                        Field { name: "email", kind: Kind::String, ..Field::default() },
+                    ],
+                    ..TableSchema::default()
+                },
+                TableSchema {
+                    table: Table { name: "comment", kind: TableKind::Normal, schema_less: true, ..Table::default() },
+                    ..TableSchema::default()
+                },
+            ],
+            ..Schema::default()
+        };
+        let diff = Diff::between(from.clone(), to.clone()).unwrap();
+        let changes: Vec<(ChangeKind,&str)> = diff.changes.iter().map(|change| (change.kind, change.statement.as_str())).collect();
+        assert_eq!(changes,vec![
+            (ChangeKind::Modifying, "REMOVE INDEX age_idx ON person"),
+            (ChangeKind::Destructive, "REMOVE FIELD age ON person"),
+            (ChangeKind::Destructive, "REMOVE TABLE article"),
+            (ChangeKind::Additive, Table::build(Table { name: "comment", kind: TableKind::Normal, schema_less: true, ..Table::default() }).unwrap().as_str()),
+            (ChangeKind::Modifying, Field::build(Field { name: "name", table: "person", kind: Kind::String, comment: "Full name", ..Field::default() }).unwrap().as_str()),
+            (ChangeKind::Additive, Field::build(Field { name: "email", table: "person", kind: Kind::String, ..Field::default() }).unwrap().as_str()),
+        ]);
+        assert!(matches!(diff.check(), Err(Error::Invalid { kind: "Diff", .. })));
+        // The diff moves a deployed schema to the desired schema.
+        let db = Db::memory("test","test").await.unwrap();
+        assert!(db.execute(&Query::new(Schema::statements(from.clone()).unwrap())).await.unwrap().is_ok());
+        let report = db.execute(&Query::new(diff.changes)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let introspection = db.introspect().await.unwrap();
+        let deployed: Diff = Diff::between(introspection.schema().unwrap(), to.clone()).unwrap();
+        assert!(deployed.is_empty(),"{:?}",deployed.changes);
+        assert!(Diff::between(to.clone(), to.clone()).unwrap().is_empty());
+        // Expecting Destructive
+        let mut schemafull: Schema = to.clone();
+        schemafull.tables[1].table.schema_less = false;
+        let diff: Diff = Diff::between(to.clone(), schemafull).unwrap();
+        assert_eq!(diff.destructive().count(),1);
+        assert!(diff.check().is_err());
+        let person = |table: Table<'static>, field: Field<'static>, index: Index<'static>| Schema {
+            tables: vec![TableSchema { table, fields: vec![field], indexes: vec![index], ..TableSchema::default() }],
+            ..Schema::default()
+        };
+        let table: Table = Table { name: "person", kind: TableKind::Normal, ..Table::default() };
+        let field: Field = Field { name: "age", kind: Kind::Int, ..Field::default() };
+        let index: Index = Index { name: "age_idx", cols: vec![Idiom::from("age")], ..Index::default() };
+        let unique: Index = Index { index: IndexType::Uniq, ..index.clone() };
+        let view: View = match surrealdb::sql::parse("DEFINE TABLE person AS SELECT * FROM user").unwrap().0.0.remove(0) {
+            Statement::Define(DefineStatement::Table(define)) => define.view.unwrap(),
+            _ => panic!("Expecting a table definition"),
+        };
+        let deployed: Schema = person(table.clone(), field.clone(), index.clone());
+        let without_index = |schema: Schema<'static>| Schema {
+            tables: schema.tables.into_iter().map(|table| TableSchema { indexes: vec![], ..table }).collect(),
+            ..Schema::default()
+        };
+        let with_user = |mut schema: Schema<'static>| {
+            schema.tables.insert(0, TableSchema { table: Table { name: "user", kind: TableKind::Normal, ..Table::default() }, ..TableSchema::default() });
+            schema
+        };
+        for (from, target, statement) in [
+            // Tables
+            (deployed.clone(), Schema::default(), "REMOVE TABLE person"),
+            (deployed.clone(), person(Table { drop: true, ..table.clone() }, field.clone(), index.clone()), "DEFINE TABLE person"),
+            (deployed.clone(), with_user(person(Table { view: Some(view), ..table.clone() }, field.clone(), index.clone())), "DEFINE TABLE person"),
+            (deployed.clone(), person(Table { schema_less: true, ..table.clone() }, field.clone(), index.clone()), "DEFINE TABLE person"),
+            (deployed.clone(), person(Table { kind: TableKind::Any, ..table.clone() }, field.clone(), index.clone()), "DEFINE TABLE person"),
+            (deployed.clone(), person(Table { permissions: Permissions::none(), ..table.clone() }, field.clone(), index.clone()), "DEFINE TABLE person"),
+            // Fields
+            (deployed.clone(), person(table.clone(), Field { name: "born", ..field.clone() }, index.clone()), "REMOVE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { kind: Kind::String, ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { flexible: true, kind: Kind::Object, ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { readonly: true, ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { assert: Some(expr::value("$value >= 0").unwrap()), ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { value: Some(expr::value("math::abs($value)").unwrap()), ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            (deployed.clone(), person(table.clone(), Field { permissions: Permissions::none(), ..field.clone() }, index.clone()), "DEFINE FIELD age"),
+            // Indexes
+            (person(table.clone(), field.clone(), unique.clone()), without_index(deployed.clone()), "REMOVE INDEX age_idx"),
+            (deployed.clone(), person(table.clone(), field.clone(), unique.clone()), "DEFINE INDEX age_idx"),
+            (person(table.clone(), field.clone(), unique.clone()), deployed.clone(), "DEFINE INDEX age_idx"),
+        ] {
+            let diff: Diff = Diff::between(from, target).unwrap();
+            let destructive: Vec<&Change> = diff.destructive().collect();
+            assert!(destructive.iter().any(|change| change.statement.starts_with(statement)),"{:?}",diff.changes);
+            assert!(diff.check().is_err());
+        }
+        // Expecting Modifying
+        let param: Param = Param { name: "max_likes", value: Value::from(100), ..Param::default() };
+        for (from, target) in [
+            (deployed.clone(), person(Table { comment: "People", ..table.clone() }, field.clone(), index.clone())),
+            (deployed.clone(), person(table.clone(), Field { comment: "Age", ..field.clone() }, index.clone())),
+            (deployed.clone(), person(table.clone(), Field { default: Some(Value::from(0)), ..field.clone() }, index.clone())),
+            (deployed.clone(), person(table.clone(), field.clone(), Index { comment: "By age", ..index.clone() })),
+            (deployed.clone(), without_index(deployed.clone())),
+            (
+                Schema { params: vec![param.clone()], ..Schema::default() },
+                Schema { params: vec![Param { comment: "Likes per article", ..param.clone() }], ..Schema::default() }
+            ),
+        ] {
+            let diff: Diff = Diff::between(from, target).unwrap();
+            assert!(!diff.is_empty() && diff.check().is_ok(),"{:?}",diff.changes);
+        }
+    }
+
+}
@@ -0,0 +1,178 @@
+use crate::builder::prelude::{ Ident, Idiom, Value, Strand };
+use crate::builder::expr;
+use crate::error::Error;
+use surrealdb::sql::statements::DefineEventStatement;
+use surrealdb::sql::{ Expression, Operator, Param, Part, Values };
+
+/// The value of the `$event` parameter inside an event.
+#[derive(Debug, Clone)]
+pub enum EventKind {
+    Create,
+    Update,
+    Delete
+}
+
+impl EventKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Create => "CREATE",
+            EventKind::Update => "UPDATE",
+            EventKind::Delete => "DELETE",
+        }
+    }
+}
+
+/// # Event
+///
+/// * `name` - The event name.
+/// * `table` - The table name.
+/// * `when` - The condition that triggers the event. See: `Event::on`, `Event::changed`.
+/// * `then` - The statements to execute when the event is triggered. Use `Event::then` to parse a statement.
+/// * `comment` - Comment on the event.
+/// * `if_not_exists` - Create or define the event if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Event<'a>{
+    pub name: &'a str,
+    pub table: &'a str,
+    pub when: Value,
+    pub then: Vec<Value>,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+}
+impl <'a> Event<'a> {
+    /// # Build a DEFINE EVENT Statement
+    /// ## Parameter
+    /// * `item` - The `Event` struct.
+    pub fn build(
+        item: Event
+    ) -> Result<String,Error> {
+        // Event =======================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Event", field: "name" });
+        }
+        if item.table.is_empty() {
+            return Err(Error::MissingName { kind: "Event", field: "table" });
+        }
+        if item.then.is_empty() {
+            return Err(Error::invalid("Event","Event requires at least one THEN statement."));
+        }
+        let mut stmt: DefineEventStatement = DefineEventStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.what           = Ident::from(item.table.to_string());
+        stmt.when           = match item.when {
+            Value::None => Value::Bool(true),
+            when => when
+        };
+        let mut then: Values = Values::default();
+        then.0              = item.then;
+        stmt.then           = then;
+        stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+
+    /// # Condition on the kind of event
+    /// Generates `$event = "CREATE"`, joined with `OR` when more than one kind is given.
+    /// ## Parameter
+    /// * `kinds` - The events which trigger the event.
+    pub fn on(kinds: &[EventKind]) -> Value {
+        kinds.iter().map(|kind| {
+            Value::from(Expression::Binary {
+                l: Value::from(Param::from("event")),
+                o: Operator::Equal,
+                r: Value::from(kind.as_str())
+            })
+        }).reduce(|l, r| {
+            Value::from(Expression::Binary { l, o: Operator::Or, r })
+        }).unwrap_or(Value::Bool(true))
+    }
+
+    /// # Condition on a changed field
+    /// Generates `$before.<field> != $after.<field>`.
+    /// ## Parameter
+    /// * `field` - The field path, e.g. `profile.email` or `tags[0]`.
+    pub fn changed(field: &str) -> Result<Value,Error> {
+        Ok(Value::from(Expression::Binary {
+            l: Event::before(field)?,
+            o: Operator::NotEqual,
+            r: Event::after(field)?
+        }))
+    }
+
+    /// # Read a field of the record before the change
+    /// Generates `$before.<field>`.
+    /// ## Parameter
+    /// * `field` - The field path, e.g. `profile.email` or `tags[0]`.
+    pub fn before(field: &str) -> Result<Value,Error> {
+        Event::param_field("before", field)
+    }
+
+    /// # Read a field of the record after the change
+    /// Generates `$after.<field>`.
+    /// ## Parameter
+    /// * `field` - The field path, e.g. `profile.email` or `tags[0]`.
+    pub fn after(field: &str) -> Result<Value,Error> {
+        Event::param_field("after", field)
+    }
+
+    /// # Parse a THEN statement
+    /// ## Parameter
+    /// * `stmt` - The SurrealQL statement or block, e.g. `CREATE log SET email = $after.email`.
+    pub fn then(stmt: &str) -> Result<Value,Error> {
+        surrealdb::sql::value(stmt).map_err(|error| Error::identifier("event statement",stmt,error))
+    }
+
+    fn param_field(param: &str, field: &str) -> Result<Value,Error> {
+        let mut parts: Vec<Part> = vec![Part::Start(Value::from(Param::from(param)))];
+        parts.extend(expr::idiom(field)?.0);
+        Ok(Value::from(Idiom::from(parts)))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let events: Vec<Event> = Vec::from([
+            Event {
+                name: "user_audit",
+                table: "user",
+                when: Event::on(&[EventKind::Create,EventKind::Update]),
+                then: vec![
+                    Event::then("CREATE audit SET email = $after.email, action = $event").unwrap()
+                ],
+                comment: "audit trail",
+                if_not_exists: true,
+            },
+            Event {
+                name: "user_email",
+                table: "user",
+                when: Event::changed("profile.email").unwrap(),
+                then: vec![
+                    Event::then("UPDATE post SET email = $after.profile.email WHERE author = $after.id").unwrap(),
+                    Event::then("{ CREATE audit SET action = 'EMAIL'; }").unwrap(),
+                ],
+                ..Event::default()
+            }
+        ]);
+        let stmts: String = Query::new(events).build().unwrap();
+        assert!(stmts.contains("WHEN $event = 'CREATE' OR $event = 'UPDATE'"));
+        assert!(stmts.contains("WHEN $before.profile.email != $after.profile.email"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        assert_eq!(Event::on(&[EventKind::Delete]).to_string(),"$event = 'DELETE'");
+        assert_eq!(Event::after("tags[0]").unwrap().to_string(),"$after.tags[0]");
+        assert_eq!(Event::before("->knows->person").unwrap().to_string(),"$before->knows->person");
+        assert_eq!(Event::changed("address.city").unwrap().to_string(),"$before.address.city != $after.address.city");
+        // Expecting Error
+        assert!(Event::build(Event { name: "no_then", table: "user", ..Event::default() }).is_err());
+        assert!(Event::then("CREATE (").is_err());
+        assert!(Event::after("address.").is_err());
+    }
+
+}
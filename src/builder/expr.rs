@@ -0,0 +1,177 @@
+//! Helpers to create the `Value`s and `Idiom`s used by the statement builders.
+use crate::builder::prelude::{ Idiom, Value, Kind, Permissions };
+use crate::error::Error;
+use surrealdb::sql::{ Cond, Field, Fields, Id, Operator, Output, Range, Statement, Timeout, Values };
+use std::ops::Bound;
+use surrealdb::sql::statements::DefineStatement;
+
+/// # Parse an expression
+/// ## Parameter
+/// * `expr` - The SurrealQL expression, e.g. `age > 18 AND active = true`.
+pub fn value(expr: &str) -> Result<Value,Error> {
+    surrealdb::sql::value(expr).map_err(|error| Error::identifier("expression",expr,error))
+}
+
+/// # Parse a field path
+/// ## Parameter
+/// * `path` - The field path, e.g. `profile.email` or `tags[0]`.
+pub fn idiom(path: &str) -> Result<Idiom,Error> {
+    surrealdb::sql::idiom(path).map_err(|error| Error::identifier("field",path,error))
+}
+
+/// # Parse a record id
+/// ## Parameter
+/// * `id` - The record id, e.g. `user:john`.
+pub fn thing(id: &str) -> Result<Value,Error> {
+    match surrealdb::sql::thing(id) {
+        Ok(thing) => Ok(Value::Thing(thing)),
+        Err(error) => Err(Error::identifier("record id",id,error))
+    }
+}
+
+/// # Record range
+/// Generates `<table>:<from>..<to>`, the range includes `from` and excludes `to`.
+/// ## Parameters
+/// * `table` - The table name.
+/// * `from` - The first record id, `None` for a range open at the start.
+/// * `to` - The record id after the range, `None` for a range open at the end.
+pub fn range(table: &str, from: Option<Value>, to: Option<Value>) -> Result<Value,Error> {
+    let bound = |id: Option<Value>, bound: fn(Id) -> Bound<Id>| -> Result<Bound<Id>,Error> {
+        match id {
+            None => Ok(Bound::Unbounded),
+            Some(Value::Number(id)) => Ok(bound(Id::from(id))),
+            Some(Value::Strand(id)) => Ok(bound(Id::from(id))),
+            Some(Value::Array(id)) => Ok(bound(Id::from(id))),
+            Some(Value::Object(id)) => Ok(bound(Id::from(id))),
+            Some(id) => Err(Error::identifier("record range",id.to_string(),"Expecting a number, string, array or object record id.")),
+        }
+    };
+    Ok(Value::Range(Box::new(Range::new(table.to_string(), bound(from, Bound::Included)?, bound(to, Bound::Excluded)?))))
+}
+
+/// # Outgoing edges of a record
+/// ## Parameters
+/// * `id` - The record id, e.g. `person:tobie`.
+/// * `edge` - The edge table, e.g. `likes`.
+pub fn edges(id: &str, edge: &str) -> Result<Value,Error> {
+    let edges: String = format!("{}->{}",id,surrealdb::sql::Table::from(edge));
+    match surrealdb::sql::value(edges.as_str()) {
+        Ok(value @ Value::Edges(_)) => Ok(value),
+        Ok(_) => Err(Error::identifier("edges",edges,"Expecting the edges of a record id, e.g. person:tobie->likes.")),
+        Err(error) => Err(Error::identifier("edges",edges,error))
+    }
+}
+
+/// # Table target
+/// ## Parameter
+/// * `name` - The table name.
+pub fn table(name: &str) -> Value {
+    Value::Table(surrealdb::sql::Table::from(name))
+}
+
+/// # Serialize a value
+/// ## Parameter
+/// * `content` - Any `serde::Serialize` value, e.g. a record struct or a slice of records.
+pub fn content<T: serde::Serialize>(content: T) -> Result<Value,Error> {
+    surrealdb::sql::to_value(content).map_err(|error| Error::invalid("Content",format!("Unable to serialize {}: {}",std::any::type_name::<T>(),error)))
+}
+
+/// # Parse a field type
+/// ## Parameter
+/// * `kind` - The SurrealQL type, e.g. `option<array<record<person>>>`.
+pub fn kind(kind: &str) -> Result<Kind,Error> {
+    match define("type",kind,&format!("DEFINE FIELD kind ON kind TYPE {}",kind)) {
+        Ok(DefineStatement::Field(define)) => define.kind.ok_or_else(|| Error::identifier("type",kind,"Expecting a type, e.g. option<string>.")),
+        Ok(_) => Err(Error::identifier("type",kind,"Expecting a single type, e.g. option<string>.")),
+        Err(error) => Err(error),
+    }
+}
+
+/// # Parse permissions
+/// ## Parameter
+/// * `permissions` - `FULL`, `NONE` or the `FOR` clauses, e.g. `FOR select FULL FOR create, update, delete WHERE user = $auth.id`.
+pub fn permissions(permissions: &str) -> Result<Permissions,Error> {
+    match define("permissions",permissions,&format!("DEFINE TABLE permissions PERMISSIONS {}",permissions)) {
+        Ok(DefineStatement::Table(define)) => Ok(define.permissions),
+        Ok(_) => Err(Error::identifier("permissions",permissions,"Expecting FULL, NONE or FOR clauses, e.g. FOR select FULL.")),
+        Err(error) => Err(error),
+    }
+}
+
+fn define(kind: &'static str, value: &str, stmt: &str) -> Result<DefineStatement,Error> {
+    let parsed: surrealdb::sql::Query = surrealdb::sql::parse(stmt).map_err(|error| Error::identifier(kind,value,error))?;
+    match parsed.0.0.into_iter().next() {
+        Some(Statement::Define(define)) => Ok(define),
+        _ => Err(Error::identifier(kind,value,format!("Unable to parse {}.",stmt))),
+    }
+}
+
+/// The `RETURN` clause of a statement.
+#[derive(Debug, Clone)]
+pub enum Return<'a> {
+    None,
+    Before,
+    After,
+    Diff,
+    /// Return only the listed field paths.
+    Fields(Vec<&'a str>),
+}
+
+/// An assignment of a `SET` or `ON DUPLICATE KEY UPDATE` clause.
+#[derive(Debug, Clone)]
+pub enum Assignment<'a> {
+    /// `field = value`
+    Set(&'a str, Value),
+    /// `field += value`
+    Add(&'a str, Value),
+    /// `field -= value`
+    Sub(&'a str, Value),
+}
+
+pub(crate) fn output(item: Return) -> Result<Output,Error> {
+    Ok(match item {
+        Return::None => Output::None,
+        Return::Before => Output::Before,
+        Return::After => Output::After,
+        Return::Diff => Output::Diff,
+        Return::Fields(paths) => {
+            if paths.is_empty() {
+                return Err(Error::invalid("Return","RETURN requires at least one field."));
+            }
+            let mut fields: Fields = Fields::default();
+            for path in paths {
+                fields.0.push(Field::Single { expr: Value::Idiom(idiom(path)?), alias: None });
+            }
+            Output::Fields(fields)
+        }
+    })
+}
+
+pub(crate) fn assignments(items: Vec<Assignment>) -> Result<Vec<(Idiom, Operator, Value)>,Error> {
+    items.into_iter().map(|item| {
+        let (path, operator, value) = match item {
+            Assignment::Set(path, value) => (path, Operator::Equal, value),
+            Assignment::Add(path, value) => (path, Operator::Inc, value),
+            Assignment::Sub(path, value) => (path, Operator::Dec, value),
+        };
+        Ok((idiom(path)?, operator, value))
+    }).collect()
+}
+
+pub(crate) fn values(items: Vec<Value>) -> Values {
+    let mut values: Values = Values::default();
+    values.0 = items;
+    values
+}
+
+pub(crate) fn cond(value: Value) -> Cond {
+    let mut cond: Cond = Cond::default();
+    cond.0 = value;
+    cond
+}
+
+pub(crate) fn timeout(duration: core::time::Duration) -> Timeout {
+    let mut timeout: Timeout = Timeout::default();
+    timeout.0 = surrealdb::sql::Duration::from(duration);
+    timeout
+}
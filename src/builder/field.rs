@@ -1,8 +1,11 @@
-use crate::builder::prelude::{ Permissions, Kind, Value, Strand, Idiom, Ident };
+use crate::builder::prelude::{ Permissions, Kind, Value, Strand, Ident };
+use crate::builder::expr;
+use crate::builder::remove::{ Remove, RemoveKind };
+use crate::error::Error;
 use surrealdb::sql::statements::DefineFieldStatement;
 /// # Field
 /// 
-/// * `name` - The field name.
+/// * `name` - The field name, or the path of a nested field, e.g. `address.city` or `tags[*]`.
 /// * `table` - The table name.
 /// * `kind` - The type of the field which is value of enum `Kind`.
 /// * `flexible` - Allow schemaless in Schemafull. See: <https://surrealdb.com/docs/surrealdb/surrealql/statements/define/field#flexible-data-types>
@@ -33,13 +36,16 @@ impl<'a> Field<'a> {
     /// * `item` - The `Field` struct.
     pub fn build(
         item: Field
-    ) -> Result<String,String> {
+    ) -> Result<String,Error> {
         // FIELD =======================================================
-        if item.name.len() == 0 {
-            return Err("Field name is required.".to_string());
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Field", field: "name" });
+        }
+        if item.table.is_empty() {
+            return Err(Error::MissingName { kind: "Field", field: "table" });
         }
         let mut stmt: DefineFieldStatement = DefineFieldStatement::default();        
-        stmt.name = Idiom::from(item.name.to_string());
+        stmt.name = expr::idiom(item.name)?;
         stmt.what = Ident::from(item.table.to_string());
         stmt.flex = item.flexible;
         stmt.kind = Some(item.kind);
@@ -49,11 +55,27 @@ impl<'a> Field<'a> {
         stmt.permissions = item.permissions;
         stmt.default = item.default;
         stmt.if_not_exists = item.if_not_exists;
-        if item.comment.len() > 0 {
+        if !item.comment.is_empty() {
             stmt.comment = Some(Strand::from(item.comment));
         }
         Ok(stmt.to_string())
     }
+
+    /// # Build the REMOVE FIELD Statement of a `Field`
+    /// The inverse of `Field::build`.
+    /// ## Parameters
+    /// * `item` - The `Field` struct.
+    /// * `if_exists` - Do not fail when the field does not exist.
+    pub fn remove(
+        item: Field,
+        if_exists: bool
+    ) -> Result<String,Error> {
+        Remove::build(Remove {
+            name: item.name,
+            kind: RemoveKind::Field(item.table),
+            if_exists,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -65,7 +87,7 @@ mod tests {
     
     #[tokio::test]
     async fn test_builder(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let table_name: &str = "test_field_define";
         match Table::build(Table {       
             name: table_name,
@@ -127,7 +149,17 @@ mod tests {
                 assert!(false,"ERROR: {}",error);
             }
         }
-        
+        // Nested fields
+        match Field::build(Field { name: "address.city", table: table_name, kind: Kind::String, ..Field::default() }) {
+            Ok(stmt) => assert_eq!(stmt,"DEFINE FIELD address.city ON test_field_define TYPE string PERMISSIONS FULL"),
+            Err(error) => assert!(false,"ERROR: {}",error)
+        }
+        match Field::build(Field { name: "tags[*]", table: table_name, kind: Kind::String, ..Field::default() }) {
+            Ok(stmt) => assert_eq!(stmt,"DEFINE FIELD tags[*] ON test_field_define TYPE string PERMISSIONS FULL"),
+            Err(error) => assert!(false,"ERROR: {}",error)
+        }
+        // Expecting Error
+        assert!(Field::build(Field { name: "address.", table: table_name, ..Field::default() }).is_err());
     }
 
 }
\ No newline at end of file
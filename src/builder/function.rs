@@ -0,0 +1,91 @@
+use crate::builder::prelude::{ Ident, Kind, Block, Value, Strand, Permission };
+use crate::error::Error;
+use surrealdb::sql::statements::DefineFunctionStatement;
+/// # Function
+///
+/// * `name` - The function name. The `fn::` namespace is added automatically, e.g. `greet` or `fn::greet` renders `fn::greet`.
+/// * `args` - The typed arguments of the function as `(Ident, Kind)` pairs. The `$` prefix is added automatically.
+/// * `block` - The body of the function. Use `Function::block` to parse it from a string.
+/// * `comment` - Comment on the function.
+/// * `permissions` - The permission to execute the function. See: <https://docs.rs/surrealdb/latest/surrealdb/sql/enum.Permission.html>
+/// * `if_not_exists` - Create or define the function if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Function<'a>{
+    pub name: &'a str,
+    pub args: Vec<(Ident, Kind)>,
+    pub block: Block,
+    pub comment: &'a str,
+    pub permissions: Permission,
+    pub if_not_exists: bool,
+}
+impl <'a> Function<'a> {
+    /// # Build a DEFINE FUNCTION Statement
+    /// ## Parameter
+    /// * `item` - The `Function` struct.
+    pub fn build(
+        item: Function
+    ) -> Result<String,Error> {
+        // Function ====================================================
+        let name: &str = item.name.strip_prefix("fn::").unwrap_or(item.name);
+        if name.is_empty() {
+            return Err(Error::MissingName { kind: "Function", field: "name" });
+        }
+        let mut stmt: DefineFunctionStatement = DefineFunctionStatement::default();
+        stmt.name           = Ident::from(name.to_string());
+        stmt.args           = item.args;
+        stmt.block          = item.block;
+        stmt.permissions    = item.permissions;
+        stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+
+    /// # Parse a function body
+    /// ## Parameter
+    /// * `body` - The SurrealQL block including the curly braces, e.g. `{ RETURN "Hello " + $name; }`.
+    pub fn block(body: &str) -> Result<Block,Error> {
+        match surrealdb::sql::value(body) {
+            Ok(Value::Block(block)) => Ok(*block),
+            Ok(_) => Err(Error::identifier("function body",body,"the body must be a block")),
+            Err(error) => Err(Error::identifier("function body",body,error)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let functions: Vec<Function> = Vec::from([
+            Function {
+                name: "greet",
+                args: vec![(Ident::from("name"), Kind::String)],
+                block: Function::block("{ RETURN 'Hello ' + $name; }").unwrap(),
+                comment: "hello function",
+                permissions: Permission::Full,
+                if_not_exists: true,
+            },
+            Function {
+                name: "fn::add",
+                args: vec![(Ident::from("a"), Kind::Number),(Ident::from("b"), Kind::Number)],
+                block: Function::block("{ RETURN $a + $b; }").unwrap(),
+                permissions: Permission::Full,
+                ..Function::default()
+            }
+        ]);
+        let stmts: String = Query::new(functions).build().unwrap();
+        assert!(stmts.contains("DEFINE FUNCTION IF NOT EXISTS fn::greet($name: string)"));
+        assert!(stmts.contains("DEFINE FUNCTION fn::add($a: number, $b: number)"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Function::build(Function { name: "fn::", ..Function::default() }).is_err());
+        assert!(Function::block("RETURN 1;").is_err());
+    }
+
+}
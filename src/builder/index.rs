@@ -1,4 +1,6 @@
-use crate::builder::prelude::{ Idioms, Idiom, Ident, IndexType };
+use crate::builder::prelude::{ Idioms, Idiom, Ident, IndexType, Strand };
+use crate::builder::remove::{ Remove, RemoveKind };
+use crate::error::Error;
 use surrealdb::sql::statements::DefineIndexStatement;
 /// # Index
 /// 
@@ -23,23 +25,45 @@ impl <'a> Index<'a> {
     /// * `item` - The `Index` struct.
     pub fn build(
         item: Index
-    ) -> Result<String,String> {
+    ) -> Result<String,Error> {
         // Index =======================================================
-        if item.name.len() == 0 {
-            return Err("Index name is required.".to_string());
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Index", field: "name" });
+        }
+        if item.table.is_empty() {
+            return Err(Error::MissingName { kind: "Index", field: "table" });
         }
         let mut stmt: DefineIndexStatement = DefineIndexStatement::default();        
         stmt.name           = Ident::from(item.name.to_string());
         stmt.what           = Ident::from(item.table.to_string());
         stmt.index          = item.index;
         stmt.if_not_exists  = item.if_not_exists;
-        if item.cols.len() > 0 {
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        if !item.cols.is_empty() {
             let mut idioms: Idioms = Idioms::default();
             idioms.0    = item.cols;
             stmt.cols   = idioms;
         }
         Ok(stmt.to_string())
     }
+
+    /// # Build the REMOVE INDEX Statement of a `Index`
+    /// The inverse of `Index::build`.
+    /// ## Parameters
+    /// * `item` - The `Index` struct.
+    /// * `if_exists` - Do not fail when the index does not exist.
+    pub fn remove(
+        item: Index,
+        if_exists: bool
+    ) -> Result<String,Error> {
+        Remove::build(Remove {
+            name: item.name,
+            kind: RemoveKind::Index(item.table),
+            if_exists,
+        })
+    }
 }
 
 
@@ -54,7 +78,7 @@ mod tests {
     
     #[tokio::test]
     async fn test_builder(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let table_name: &str = "test_table_index";
         match Table::build(Table {       
             name: table_name,
@@ -0,0 +1,171 @@
+use crate::builder::prelude::{ Idiom, Value };
+use crate::builder::expr::{ self, Assignment, Return };
+use crate::error::Error;
+use surrealdb::sql::statements::InsertStatement;
+use surrealdb::sql::Data;
+/// # Insert
+///
+/// * `into` - The table name.
+/// * `ignore` - Skip the records which already exist instead of failing.
+/// * `content` - A record object or an array of record objects. See: `expr::content` to serialize a `serde::Serialize` value.
+/// * `fields` - The field names of the `VALUES` rows.
+/// * `values` - The `VALUES` rows, one value per field. Only one of `content` or `values` can be set.
+/// * `on_duplicate` - The `ON DUPLICATE KEY UPDATE` assignments.
+/// * `output` - The `RETURN` clause.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Insert the records in parallel.
+#[derive(Debug, Clone, Default)]
+pub struct Insert<'a>{
+    pub into: &'a str,
+    pub ignore: bool,
+    pub content: Option<Value>,
+    pub fields: Vec<&'a str>,
+    pub values: Vec<Vec<Value>>,
+    pub on_duplicate: Vec<Assignment<'a>>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+}
+impl <'a> Insert<'a> {
+    /// # Build an INSERT Statement
+    /// ## Parameter
+    /// * `item` - The `Insert` struct.
+    pub fn build(
+        item: Insert
+    ) -> Result<String,Error> {
+        // Insert ======================================================
+        if item.into.is_empty() {
+            return Err(Error::MissingName { kind: "Insert", field: "table" });
+        }
+        if item.ignore && !item.on_duplicate.is_empty() {
+            return Err(Error::invalid("Insert","Insert cannot use both IGNORE and ON DUPLICATE KEY UPDATE."));
+        }
+        let mut stmt: InsertStatement = InsertStatement::default();
+        stmt.into       = expr::table(item.into);
+        stmt.ignore     = item.ignore;
+        stmt.parallel   = item.parallel;
+        stmt.data       = match (item.content, item.values.is_empty()) {
+            (Some(_), false) => return Err(Error::invalid("Insert","Insert cannot use both content and VALUES.")),
+            (None, true) => return Err(Error::invalid("Insert","Insert requires content or VALUES.")),
+            (Some(content), true) => Data::SingleExpression(content),
+            (None, false) => {
+                if item.fields.is_empty() {
+                    return Err(Error::invalid("Insert","Insert VALUES requires at least one field."));
+                }
+                let fields: Vec<Idiom> = item.fields.iter()
+                    .map(|path| expr::idiom(path))
+                    .collect::<Result<Vec<Idiom>,Error>>()?;
+                let mut rows: Vec<Vec<(Idiom, Value)>> = Vec::with_capacity(item.values.len());
+                for (index, row) in item.values.into_iter().enumerate() {
+                    if row.len() != fields.len() {
+                        return Err(Error::invalid("Insert",format!("Insert VALUES row {} has {} values, expected {}.",index,row.len(),fields.len())));
+                    }
+                    rows.push(fields.iter().cloned().zip(row).collect());
+                }
+                Data::ValuesExpression(rows)
+            }
+        };
+        if !item.on_duplicate.is_empty() {
+            stmt.update = Some(Data::UpdateExpression(expr::assignments(item.on_duplicate)?));
+        }
+        if let Some(output) = item.output {
+            stmt.output = Some(expr::output(output)?);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::db::Db;
+    use serde::Serialize;
+
+    #[derive(Debug, Serialize)]
+    struct Product {
+        sku: String,
+        stock: i64,
+    }
+
+    #[test]
+    fn test_builder(){
+        let products: Vec<Product> = (1..=3).map(|index| Product {
+            sku: format!("sku-{}",index),
+            stock: index * 10,
+        }).collect();
+        let stmt: String = Insert::build(Insert {
+            into: "product",
+            content: Some(expr::content(&products).unwrap()),
+            on_duplicate: vec![Assignment::Add("stock",Value::from(1))],
+            output: Some(Return::Diff),
+            ..Insert::default()
+        }).unwrap();
+        assert_eq!(stmt,"INSERT INTO product [{ sku: 'sku-1', stock: 10 }, { sku: 'sku-2', stock: 20 }, { sku: 'sku-3', stock: 30 }] ON DUPLICATE KEY UPDATE stock += 1 RETURN DIFF");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        let stmt: String = Insert::build(Insert {
+            into: "product",
+            ignore: true,
+            fields: vec!["sku","stock"],
+            values: vec![
+                vec![Value::from("sku-4"),Value::from(40)],
+                vec![Value::from("sku-5"),Value::from(50)],
+            ],
+            output: Some(Return::None),
+            ..Insert::default()
+        }).unwrap();
+        assert_eq!(stmt,"INSERT IGNORE INTO product (sku, stock) VALUES ('sku-4', 40), ('sku-5', 50) RETURN NONE");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        // Expecting Error
+        assert!(Insert::build(Insert { into: "product", ..Insert::default() }).is_err());
+        assert!(Insert::build(Insert {
+            into: "product",
+            fields: vec!["sku","stock"],
+            values: vec![vec![Value::from("sku-6")]],
+            ..Insert::default()
+        }).is_err());
+        assert!(Insert::build(Insert {
+            into: "product",
+            ignore: true,
+            content: Some(Value::from(1)),
+            on_duplicate: vec![Assignment::Set("stock",Value::from(0))],
+            ..Insert::default()
+        }).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        let products: Vec<Product> = (1..=3).map(|index| Product {
+            sku: format!("sku-{}",index),
+            stock: index * 10,
+        }).collect();
+        let inserts: Vec<Insert> = Vec::from([
+            Insert {
+                into: "product",
+                content: Some(expr::content(&products).unwrap()),
+                output: Some(Return::None),
+                ..Insert::default()
+            },
+            Insert {
+                into: "product",
+                fields: vec!["sku","stock"],
+                values: vec![
+                    vec![Value::from("sku-4"),Value::from(40)],
+                    vec![Value::from("sku-5"),Value::from(50)],
+                ],
+                output: Some(Return::None),
+                ..Insert::default()
+            }
+        ]);
+        let report = db.execute(&Query::new(inserts)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let stock: Option<i64> = db.query("RETURN math::sum(SELECT VALUE stock FROM product)").await.unwrap().take(0).unwrap();
+        assert_eq!(stock,Some(150));
+    }
+
+}
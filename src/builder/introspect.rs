@@ -0,0 +1,283 @@
+use crate::builder::prelude::{ Kind, TableKind, Filter };
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::index::Index;
+use crate::builder::event::Event;
+use crate::builder::param::Param;
+use crate::builder::function::Function;
+use crate::builder::analyzer::Analyzer;
+use crate::builder::schema::{ Schema, TableSchema };
+use crate::error::Error;
+use surrealdb::sql::statements::{
+    DefineStatement, DefineTableStatement, DefineFieldStatement, DefineIndexStatement,
+    DefineEventStatement, DefineParamStatement, DefineFunctionStatement, DefineAnalyzerStatement
+};
+use surrealdb::sql::{ Statement, Strand, TableType };
+use std::collections::BTreeMap;
+
+/// # TableIntrospection
+///
+/// * `table` - The DEFINE TABLE statement.
+/// * `fields` - The DEFINE FIELD statements keyed by the field name.
+/// * `indexes` - The DEFINE INDEX statements keyed by the index name.
+/// * `events` - The DEFINE EVENT statements keyed by the event name.
+#[derive(Debug, Clone, Default)]
+pub struct TableIntrospection {
+    pub table: DefineTableStatement,
+    pub fields: BTreeMap<String, DefineFieldStatement>,
+    pub indexes: BTreeMap<String, DefineIndexStatement>,
+    pub events: BTreeMap<String, DefineEventStatement>,
+}
+
+/// # Introspection
+/// The definitions deployed in a database, as returned by `INFO FOR DB` and `INFO FOR TABLE`. See: `Db::introspect`.
+/// Users, scopes, tokens and models are not part of a `Schema` and are not collected.
+///
+/// * `params` - The DEFINE PARAM statements keyed by the param name.
+/// * `functions` - The DEFINE FUNCTION statements keyed by the function name.
+/// * `analyzers` - The DEFINE ANALYZER statements keyed by the analyzer name.
+/// * `tables` - The definitions of the tables keyed by the table name.
+#[derive(Debug, Clone, Default)]
+pub struct Introspection {
+    pub params: BTreeMap<String, DefineParamStatement>,
+    pub functions: BTreeMap<String, DefineFunctionStatement>,
+    pub analyzers: BTreeMap<String, DefineAnalyzerStatement>,
+    pub tables: BTreeMap<String, TableIntrospection>,
+}
+
+impl Introspection {
+    /// # Add a Definition
+    /// Parses the statement and files it under its kind, fields, indexes and events under their table.
+    /// ## Parameter
+    /// * `stmt` - A DEFINE PARAM, FUNCTION, ANALYZER, TABLE, FIELD, INDEX or EVENT statement.
+    pub fn insert(&mut self, stmt: &str) -> Result<(),Error> {
+        let parsed: surrealdb::sql::Query = surrealdb::sql::parse(stmt)
+            .map_err(|error| Error::invalid("Introspection",format!("Invalid definition {}: {}",stmt,error)))?;
+        let mut stmts = parsed.0.0.into_iter();
+        let define: DefineStatement = match (stmts.next(), stmts.next()) {
+            (Some(Statement::Define(define)), None) => define,
+            _ => return Err(Error::invalid("Introspection",format!("Invalid definition {}, expecting a single DEFINE statement.",stmt))),
+        };
+        match define {
+            DefineStatement::Param(define) => {
+                self.params.insert(define.name.0.clone(), define);
+            }
+            DefineStatement::Function(define) => {
+                self.functions.insert(define.name.0.clone(), define);
+            }
+            DefineStatement::Analyzer(define) => {
+                self.analyzers.insert(define.name.0.clone(), define);
+            }
+            DefineStatement::Table(define) => {
+                let name: String = define.name.0.clone();
+                self.entry(&name).table = define;
+            }
+            DefineStatement::Field(define) => {
+                self.entry(&define.what.0).fields.insert(define.name.to_string(), define);
+            }
+            DefineStatement::Index(define) => {
+                self.entry(&define.what.0).indexes.insert(define.name.0.clone(), define);
+            }
+            DefineStatement::Event(define) => {
+                self.entry(&define.what.0).events.insert(define.name.0.clone(), define);
+            }
+            _ => return Err(Error::invalid("Introspection",format!("Invalid definition {}, expecting a DEFINE PARAM, FUNCTION, ANALYZER, TABLE, FIELD, INDEX or EVENT statement.",stmt))),
+        }
+        Ok(())
+    }
+
+    /// # The Schema of the Definitions
+    /// Converts the definitions back into builders, e.g. to compare environments or to bootstrap the builders of an existing database.
+    /// Fails when a definition cannot be expressed by its builder, e.g. a relation table with more than one `IN` table.
+    pub fn schema(&self) -> Result<Schema<'_>,Error> {
+        Ok(Schema {
+            params: self.params.values().map(Introspection::param).collect(),
+            functions: self.functions.values().map(Introspection::function).collect(),
+            analyzers: self.analyzers.values().map(Introspection::analyzer).collect::<Result<_,Error>>()?,
+            tables: self.tables.values().map(|table| Ok(TableSchema {
+                table: Introspection::table(&table.table)?,
+                fields: table.fields.iter().map(|(name, field)| Introspection::field(name, field)).collect(),
+                indexes: table.indexes.values().map(Introspection::index).collect(),
+                events: table.events.values().map(Introspection::event).collect(),
+            })).collect::<Result<_,Error>>()?,
+        })
+    }
+
+    /// The definitions of a table, added before the DEFINE TABLE statement when needed.
+    fn entry(&mut self, name: &str) -> &mut TableIntrospection {
+        let table: &mut TableIntrospection = self.tables.entry(name.to_string()).or_default();
+        if table.table.name.is_empty() {
+            table.table.name = name.into();
+        }
+        table
+    }
+
+    fn comment(comment: &Option<Strand>) -> &str {
+        comment.as_ref().map(|comment| comment.0.as_str()).unwrap_or_default()
+    }
+
+    /// The single table of the `IN` or `OUT` of a relation.
+    fn relation<'a>(table: &str, kind: &'a Option<Kind>) -> Result<&'a str,Error> {
+        match kind {
+            None => Ok(""),
+            Some(Kind::Record(tables)) if tables.len() <= 1 => Ok(tables.first().map(|table| table.0.as_str()).unwrap_or_default()),
+            Some(kind) => Err(Error::invalid("Table",format!("The relation {} of table {} cannot be expressed by a Table.",kind,table))),
+        }
+    }
+
+    fn table(define: &DefineTableStatement) -> Result<Table<'_>,Error> {
+        let (kind, relation_in, relation_out) = match &define.kind {
+            TableType::Normal => (TableKind::Normal, "", ""),
+            TableType::Relation(relation) => (
+                TableKind::Relation,
+                Introspection::relation(&define.name, &relation.from)?,
+                Introspection::relation(&define.name, &relation.to)?,
+            ),
+            _ => (TableKind::Any, "", ""),
+        };
+        Ok(Table {
+            name: &define.name,
+            kind,
+            drop: define.drop,
+            schema_less: !define.full,
+            changefeed: define.changefeed.map(|changefeed| changefeed.expiry.as_secs()).unwrap_or_default(),
+            include_original: define.changefeed.map(|changefeed| changefeed.store_original).unwrap_or_default(),
+            permissions: define.permissions.clone(),
+            comment: Introspection::comment(&define.comment),
+            relation_in,
+            relation_out,
+            if_not_exists: define.if_not_exists,
+            view: define.view.clone(),
+        })
+    }
+
+    fn field<'a>(name: &'a str, define: &'a DefineFieldStatement) -> Field<'a> {
+        Field {
+            name,
+            table: &define.what,
+            kind: define.kind.clone().unwrap_or(Kind::Any),
+            flexible: define.flex,
+            readonly: define.readonly,
+            default: define.default.clone(),
+            assert: define.assert.clone(),
+            value: define.value.clone(),
+            permissions: define.permissions.clone(),
+            comment: Introspection::comment(&define.comment),
+            if_not_exists: define.if_not_exists,
+        }
+    }
+
+    fn index(define: &DefineIndexStatement) -> Index<'_> {
+        Index {
+            name: &define.name,
+            table: &define.what,
+            cols: define.cols.0.clone(),
+            index: define.index.clone(),
+            comment: Introspection::comment(&define.comment),
+            if_not_exists: define.if_not_exists,
+        }
+    }
+
+    fn event(define: &DefineEventStatement) -> Event<'_> {
+        Event {
+            name: &define.name,
+            table: &define.what,
+            when: define.when.clone(),
+            then: define.then.0.clone(),
+            comment: Introspection::comment(&define.comment),
+            if_not_exists: define.if_not_exists,
+        }
+    }
+
+    fn param(define: &DefineParamStatement) -> Param<'_> {
+        Param {
+            name: &define.name,
+            value: define.value.clone(),
+            comment: Introspection::comment(&define.comment),
+            permission: define.permissions.clone(),
+            if_not_exists: define.if_not_exists,
+        }
+    }
+
+    fn function(define: &DefineFunctionStatement) -> Function<'_> {
+        Function {
+            name: &define.name,
+            args: define.args.clone(),
+            block: define.block.clone(),
+            comment: Introspection::comment(&define.comment),
+            permissions: define.permissions.clone(),
+            if_not_exists: define.if_not_exists,
+        }
+    }
+
+    fn analyzer(define: &DefineAnalyzerStatement) -> Result<Analyzer<'_>,Error> {
+        Ok(Analyzer {
+            name: &define.name,
+            function: define.function.as_ref().map(|function| function.0.as_str()).unwrap_or_default(),
+            tokenizers: define.tokenizers.clone().unwrap_or_default(),
+            filters: define.filters.iter().flatten()
+                .map(|filter| filter.to_string().parse::<Filter>())
+                .collect::<Result<_,Error>>()?,
+            comment: Introspection::comment(&define.comment),
+            if_not_exists: define.if_not_exists,
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::statement::Statement;
+    use crate::db::Db;
+
+    #[tokio::test]
+    async fn test_introspect(){
+        let db = Db::memory("test","test").await.unwrap();
+        let definitions: Vec<&str> = Vec::from([
+            "DEFINE PARAM $max_likes VALUE 100",
+            "DEFINE FUNCTION fn::normalize($text: string) { RETURN string::lowercase($text); }",
+            "DEFINE ANALYZER english TOKENIZERS BLANK,CLASS FILTERS LOWERCASE,EDGENGRAM(1,3),SNOWBALL(ENGLISH)",
+            "DEFINE TABLE person SCHEMAFULL PERMISSIONS FOR select FULL, FOR create, update, delete NONE",
+            "DEFINE TABLE article SCHEMAFULL CHANGEFEED 1h COMMENT 'Articles'",
+            "DEFINE TABLE likes TYPE RELATION IN person OUT article SCHEMALESS",
+            "DEFINE FIELD name ON person TYPE string ASSERT string::len($value) > 0",
+            "DEFINE FIELD address ON person TYPE object",
+            "DEFINE FIELD address.city ON person TYPE string",
+            "DEFINE FIELD tags ON person TYPE array",
+            "DEFINE FIELD tags[*] ON person TYPE string",
+            "DEFINE FIELD body ON article TYPE option<string> DEFAULT 'empty'",
+            "DEFINE INDEX body_search ON article FIELDS body SEARCH ANALYZER english BM25",
+            "DEFINE EVENT article_created ON article WHEN $event = 'CREATE' THEN (CREATE log SET article = $after.id)",
+        ]);
+        assert!(db.query(definitions.join(";")).await.unwrap().check().is_ok());
+        let introspection = db.introspect().await.unwrap();
+        assert_eq!(introspection.params.len(),1);
+        assert_eq!(introspection.functions.len(),1);
+        assert_eq!(introspection.analyzers.len(),1);
+        assert_eq!(introspection.tables.keys().collect::<Vec<&String>>(),vec!["article","likes","person"]);
+        assert!(introspection.tables["article"].fields.contains_key("body"));
+        assert!(introspection.tables["article"].indexes.contains_key("body_search"));
+        assert!(introspection.tables["article"].events.contains_key("article_created"));
+        assert_eq!(introspection.tables["person"].fields.keys().collect::<Vec<&String>>(),vec!["address","address.city","name","tags","tags[*]"]);
+        let schema: Schema = introspection.schema().unwrap();
+        let likes: &Table = &schema.tables.iter().find(|table| table.table.name == "likes").unwrap().table;
+        assert!(matches!(likes.kind, TableKind::Relation));
+        assert_eq!((likes.relation_in, likes.relation_out),("person","article"));
+        assert_eq!(schema.analyzers[0].filters.len(),3);
+        // The schema deploys to another database and introspects the same.
+        let copy = Db::memory("test","copy").await.unwrap();
+        let stmts: String = schema.statement().unwrap();
+        assert!(copy.query(stmts.as_str()).await.unwrap().check().is_ok(),"{}",stmts);
+        assert!(stmts.contains("DEFINE FIELD address.city ON person TYPE string") && stmts.contains("DEFINE FIELD tags[*] ON person TYPE string"),"{}",stmts);
+        let copied: Introspection = copy.introspect().await.unwrap();
+        assert_eq!(copied.tables["person"].fields.len(),5);
+        assert_eq!(copied.schema().unwrap().statement().unwrap(),stmts);
+        // Expecting Error
+        let mut introspection: Introspection = Introspection::default();
+        assert!(introspection.insert("DEFINE TABLE pair TYPE RELATION IN person | article OUT article").is_ok());
+        assert!(matches!(introspection.schema(), Err(Error::Invalid { kind: "Table", .. })));
+        assert!(introspection.insert("SELECT * FROM person").is_err());
+    }
+
+}
@@ -3,4 +3,27 @@ pub mod table;
 pub mod field;
 pub mod index;
 pub mod param;
+pub mod function;
+pub mod event;
+pub mod analyzer;
+pub mod namespace;
+pub mod database;
+pub mod user;
+pub mod token;
+pub mod scope;
+pub mod expr;
+pub mod select;
+pub mod create;
+pub mod insert;
+pub mod update;
+pub mod delete;
+pub mod relate;
+pub mod upsert;
+pub mod remove;
+pub mod transaction;
+pub mod statement;
+pub mod derive;
+pub mod schema;
+pub mod introspect;
+pub mod diff;
 pub mod query;
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::builder::prelude::{ Ident, Strand };
+use crate::error::Error;
+use surrealdb::sql::statements::DefineNamespaceStatement;
+/// # Namespace
+///
+/// * `name` - The namespace name.
+/// * `comment` - Comment on the namespace.
+/// * `if_not_exists` - Create or define the namespace if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Namespace<'a>{
+    pub name: &'a str,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+}
+impl <'a> Namespace<'a> {
+    /// # Build a DEFINE NAMESPACE Statement
+    /// ## Parameter
+    /// * `item` - The `Namespace` struct.
+    pub fn build(
+        item: Namespace
+    ) -> Result<String,Error> {
+        // Namespace ===================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Namespace", field: "name" });
+        }
+        let mut stmt: DefineNamespaceStatement = DefineNamespaceStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let namespaces: Vec<Namespace> = Vec::from([
+            Namespace {
+                name: "tenant_a",
+                comment: "Tenant A",
+                if_not_exists: true,
+            },
+            Namespace {
+                name: "tenant_b",
+                ..Namespace::default()
+            }
+        ]);
+        let stmts: String = Query::new(namespaces).build().unwrap();
+        assert_eq!(stmts,"DEFINE NAMESPACE IF NOT EXISTS tenant_a COMMENT 'Tenant A';DEFINE NAMESPACE tenant_b;");
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Namespace::build(Namespace::default()).is_err());
+    }
+
+}
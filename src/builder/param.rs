@@ -1,11 +1,13 @@
-use crate::builder::prelude::{ Ident, Value, Permission };
+use crate::builder::prelude::{ Ident, Value, Permission, Strand };
+use crate::builder::remove::{ Remove, RemoveKind };
+use crate::error::Error;
 use surrealdb::sql::statements::DefineParamStatement;
 /// # Param
 /// 
 /// * `name` - The name of the parameter.
 /// * `value` - The value of the parameter.
 /// * `index` - The type of indexing. See: <https://docs.rs/surrealdb/latest/surrealdb/sql/statements/struct.DefineParamStatement.html>
-/// * `comment` - Comment on the index.
+/// * `comment` - Comment on the parameter.
 /// * `permission` - The permission of the parameter. See: <https://docs.rs/surrealdb/latest/surrealdb/sql/enum.Permission.html>
 /// * `if_not_exists` - Create or define the index if not exists.
 #[derive(Debug, Clone, Default)]
@@ -22,18 +24,37 @@ impl <'a> Param<'a> {
     /// * `item` - The `Param` struct.
     pub fn build(
         item: Param
-    ) -> Result<String,String> {
+    ) -> Result<String,Error> {
         // Param =======================================================
-        if item.name.len() == 0 {
-            return Err("Param name is required.".to_string());
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Param", field: "name" });
         }
         let mut stmt: DefineParamStatement = DefineParamStatement::default();        
         stmt.name           = Ident::from(item.name.to_string());
         stmt.value          = item.value;
         stmt.permissions    = item.permission;
         stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
         Ok(stmt.to_string())
     }
+
+    /// # Build the REMOVE PARAM Statement of a `Param`
+    /// The inverse of `Param::build`.
+    /// ## Parameters
+    /// * `item` - The `Param` struct.
+    /// * `if_exists` - Do not fail when the param does not exist.
+    pub fn remove(
+        item: Param,
+        if_exists: bool
+    ) -> Result<String,Error> {
+        Remove::build(Remove {
+            name: item.name,
+            kind: RemoveKind::Param,
+            if_exists,
+        })
+    }
 }
 
 
@@ -45,7 +66,7 @@ mod tests {
     
     #[tokio::test]
     async fn test_builder(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let params: Vec<Param> = Vec::from([
             Param {       
                 name: "myparam_string",
@@ -1,6 +1,20 @@
 pub use surrealdb::sql::{
     Ident, Idiom, Strand, Value, Kind, Permission, Permissions, View, ChangeFeed,
-    Relation, Idioms
+    Relation, Idioms, Block, Tokenizer, Algorithm
 };
 pub use crate::builder::table::TableKind;
+pub use crate::builder::event::EventKind;
+pub use crate::builder::analyzer::{ Filter, Language };
+pub use crate::builder::user::{ UserLevel, UserRole };
+pub use crate::builder::token::TokenLevel;
+pub use crate::builder::expr::{ Return, Assignment };
+pub use crate::builder::update::{ UpdateData, PatchOp };
+pub use crate::builder::upsert::SurrealVersion;
+pub use crate::builder::remove::RemoveKind;
+pub use crate::builder::transaction::Guard;
+pub use crate::builder::statement::Statement;
+pub use crate::builder::diff::ChangeKind;
+pub use crate::builder::derive::SurrealTable;
+#[cfg(feature = "derive")]
+pub use surrealqb_derive::SurrealTable;
 pub type IndexType = surrealdb::sql::Index;
\ No newline at end of file
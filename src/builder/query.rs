@@ -1,7 +1,7 @@
-use crate::builder::table::Table;
-use crate::builder::field::Field;
 use crate::builder::index::Index;
-use crate::builder::param::Param;
+use crate::builder::analyzer::Analyzer;
+use crate::builder::statement::Statement;
+use crate::error::Error;
 
 /// Build query statements.
 #[derive(Debug, Clone)]
@@ -116,69 +116,76 @@ impl<T> Query<T> {
         }
     }
 
-    pub fn get_statement(&mut self, item: Result<String,String>) -> Result<(),String> {
+    /// Add the statement of the next item, a failure carries the index of the item in the batch.
+    pub fn get_statement(&mut self, item: Result<String,Error>) -> Result<(),Error> {
         match item {
             Ok(stmt) => {
                 self.stmts.push(stmt);
                 Ok(())
             },
-            Err(error) => { return  Err(format!("QUERY STATEMENT ERROR: {}",error)) }
+            Err(error) => Err(Error::Query { index: self.stmts.len(), error: Box::new(error) })
         }
     }
-
-    pub fn to_string(&self) -> String {
-        format!("{};",self.stmts.join(";").to_string())
-    }
 }
 
-impl<'a> Query<Field<'a>>{
-    pub fn build(&mut self) -> Result<String,String>{
-        for item in self.items.clone() {
-            self.get_statement(Field::build(item))?
-        }
-        Ok(self.to_string())
+impl<T> std::fmt::Display for Query<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{};", self.stmts.join(";"))
     }
 }
 
-impl<'a> Query<Index<'a>>{
-    pub fn build(&mut self) -> Result<String,String>{
-        for item in self.items.clone() {
-            self.get_statement(Index::build(item))?
+impl<T: Statement> Query<T>{
+    /// Build the statements of the items in order, the statements of a previous build are replaced.
+    /// A search index must name an analyzer defined before it in the query, see `Db::execute` for the analyzers of the database.
+    pub fn build(&mut self) -> Result<String,Error>{
+        self.stmts.clear();
+        let mut analyzers: Vec<String> = Vec::new();
+        for index in 0..self.items.len() {
+            let stmt: Result<String,Error> = self.items[index].statement()
+                .and_then(|stmt| {
+                    // A statement the parser rejects, e.g. an UPSERT of SurrealDB 2.x, defines no analyzer.
+                    if let Ok(parsed) = surrealdb::sql::parse(&stmt) {
+                        Analyzer::check(&parsed.0.0, &mut analyzers)?;
+                    }
+                    Ok(stmt)
+                });
+            self.get_statement(stmt)?
         }
         Ok(self.to_string())
     }
 }
 
-impl<'a> Query<Table<'a>>{
-    pub fn build(&mut self) -> Result<String,String>{
+impl<'a> Query<Analyzer<'a>>{
+    /// Build the analyzers followed by the indexes which use them.
+    /// Fails when a search index names an analyzer that is not in the batch.
+    pub fn build_with_indexes(&mut self, indexes: Vec<Index<'a>>) -> Result<String,Error>{
+        self.stmts.clear();
         for item in self.items.clone() {
-            self.get_statement(Table::build(item))?
+            self.get_statement(Analyzer::build(item))?
         }
-        Ok(self.to_string())
-    }
-}
-
-impl<'a> Query<Param<'a>>{
-    pub fn build(&mut self) -> Result<String,String>{
-        for item in self.items.clone() {
-            self.get_statement(Param::build(item))?
+        for item in indexes {
+            let stmt: Result<String,Error> = Analyzer::validate(&self.items, std::slice::from_ref(&item))
+                .and_then(|_| Index::build(item));
+            self.get_statement(stmt)?
         }
         Ok(self.to_string())
     }
 }
 
-
 #[cfg(test)]
 mod test {
     use std::collections::HashMap;
 
     use super::*;
+    use crate::builder::table::Table;
+    use crate::builder::field::Field;
+    use crate::builder::param::Param;
     use crate::db::Db;
     use crate::builder::prelude::*;
 
     #[tokio::test]
     async fn test_fields(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let table_name: &str = "test_field_define";
         match Table::build(Table {       
             name: table_name,
@@ -233,7 +240,7 @@ mod test {
     }
     #[tokio::test]
     async fn test_indexes(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let table_name: &str = "test_table_indexes";
         match Table::build(Table {       
             name: table_name,
@@ -326,7 +333,7 @@ mod test {
 
     #[tokio::test]
     async fn test_tables(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let tables: Vec<Table> = Vec::from([
             Table {       
                 name: "my_table_normal_1",
@@ -362,7 +369,7 @@ mod test {
     
     #[tokio::test]
     async fn test_params(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let params: Vec<Param> = Vec::from([
             Param  {       
                 name: "myparam_via_query_string",
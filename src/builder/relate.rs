@@ -0,0 +1,218 @@
+use crate::builder::prelude::{ Value, TableKind };
+use crate::builder::expr::{ self, Assignment, Return };
+use crate::builder::table::Table;
+use crate::error::Error;
+use surrealdb::sql::statements::RelateStatement;
+use surrealdb::sql::Data;
+/// # Relate
+///
+/// * `from` - The incoming record id, array of record ids or subquery. See: `expr::thing`, `expr::value`.
+/// * `edge` - The edge table name.
+/// * `with` - The outgoing record id, array of record ids or subquery. See: `expr::thing`, `expr::value`.
+/// * `only` - Return a single edge instead of an array.
+/// * `content` - The content of the edge. See: `expr::content` to serialize a `serde::Serialize` value.
+/// * `set` - The fields of the edge. Only one of `content` or `set` can be set.
+/// * `output` - The `RETURN` clause.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Create the edges in parallel.
+/// * `relation` - The `Table` definition of the edge table. When set, the edge table and the record ids of `from` and `with` are validated against it.
+#[derive(Debug, Clone, Default)]
+pub struct Relate<'a>{
+    pub from: Value,
+    pub edge: &'a str,
+    pub with: Value,
+    pub only: bool,
+    pub content: Option<Value>,
+    pub set: Vec<Assignment<'a>>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+    pub relation: Option<Table<'a>>,
+}
+impl <'a> Relate<'a> {
+    /// # Build a RELATE Statement
+    /// ## Parameter
+    /// * `item` - The `Relate` struct.
+    pub fn build(
+        item: Relate
+    ) -> Result<String,Error> {
+        // Relate ======================================================
+        if item.edge.is_empty() {
+            return Err(Error::MissingName { kind: "Relate", field: "edge" });
+        }
+        if item.from.is_none() || item.with.is_none() {
+            return Err(Error::invalid("Relate","Relate requires both the from and with records."));
+        }
+        if item.content.is_some() && !item.set.is_empty() {
+            return Err(Error::invalid("Relate","Relate cannot use both CONTENT and SET."));
+        }
+        if let Some(relation) = &item.relation {
+            Relate::validate(relation, item.edge, &item.from, &item.with)?;
+        }
+        let mut stmt: RelateStatement = RelateStatement::default();
+        stmt.kind       = expr::table(item.edge);
+        stmt.from       = item.from;
+        stmt.with       = item.with;
+        stmt.only       = item.only;
+        stmt.parallel   = item.parallel;
+        if let Some(content) = item.content {
+            stmt.data = Some(Data::ContentExpression(content));
+        }
+        if !item.set.is_empty() {
+            stmt.data = Some(Data::SetExpression(expr::assignments(item.set)?));
+        }
+        if let Some(output) = item.output {
+            stmt.output = Some(expr::output(output)?);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        Ok(stmt.to_string())
+    }
+
+    /// Check the edge table and the record ids against the relation table definition.
+    /// Subqueries are not checked.
+    fn validate(
+        relation: &Table,
+        edge: &str,
+        from: &Value,
+        with: &Value
+    ) -> Result<(),Error> {
+        if !matches!(relation.kind, TableKind::Relation) {
+            return Err(Error::InvalidRelation(format!("Table {} is not a relation table.",relation.name)));
+        }
+        if relation.name != edge {
+            return Err(Error::InvalidRelation(format!("Relate edge {} does not match the relation table {}.",edge,relation.name)));
+        }
+        for (value, table, side) in [(from, relation.relation_in, "in"), (with, relation.relation_out, "out")] {
+            if table.is_empty() {
+                continue;
+            }
+            let things: Vec<&Value> = match value {
+                Value::Array(array) => array.iter().collect(),
+                value => vec![value],
+            };
+            for thing in things {
+                if let Value::Thing(thing) = thing {
+                    if thing.tb != table {
+                        return Err(Error::InvalidRelation(format!("Relate record {} does not match the relation {} table {}.",thing,side,table)));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::db::Db;
+
+    #[test]
+    fn test_builder(){
+        let relation: Table = Table {
+            name: "wrote",
+            kind: TableKind::Relation,
+            relation_in: "person",
+            relation_out: "article",
+            ..Table::default()
+        };
+        let relates: Vec<Relate> = Vec::from([
+            Relate {
+                from: expr::thing("person:tobie").unwrap(),
+                edge: "wrote",
+                with: expr::thing("article:surreal").unwrap(),
+                only: true,
+                set: vec![Assignment::Set("time.written",expr::value("time::now()").unwrap())],
+                output: Some(Return::After),
+                relation: Some(relation.clone()),
+                ..Relate::default()
+            },
+            Relate {
+                from: Value::from(vec![expr::thing("person:tobie").unwrap(),expr::thing("person:jaime").unwrap()]),
+                edge: "wrote",
+                with: expr::value("(SELECT VALUE id FROM article WHERE draft = false)").unwrap(),
+                content: Some(expr::value("{ source: 'import' }").unwrap()),
+                output: Some(Return::None),
+                relation: Some(relation.clone()),
+                ..Relate::default()
+            },
+            Relate {
+                from: expr::thing("person:tobie").unwrap(),
+                edge: "likes",
+                with: expr::thing("person:jaime").unwrap(),
+                ..Relate::default()
+            }
+        ]);
+        let stmts: String = Query::new(relates).build().unwrap();
+        assert!(stmts.contains("RELATE ONLY person:tobie -> wrote -> article:surreal SET time.written = time::now() RETURN AFTER;"));
+        assert!(stmts.contains("RELATE [person:tobie, person:jaime] -> wrote -> (SELECT VALUE id FROM article WHERE draft = false) CONTENT { source: 'import' } RETURN NONE;"));
+        assert!(stmts.contains("RELATE person:tobie -> likes -> person:jaime;"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Relate::build(Relate { edge: "wrote", ..Relate::default() }).is_err());
+        assert!(Relate::build(Relate {
+            from: expr::thing("person:tobie").unwrap(),
+            edge: "likes",
+            with: expr::thing("article:surreal").unwrap(),
+            relation: Some(relation.clone()),
+            ..Relate::default()
+        }).is_err());
+        assert!(Relate::build(Relate {
+            from: expr::thing("article:surreal").unwrap(),
+            edge: "wrote",
+            with: expr::thing("article:surreal").unwrap(),
+            relation: Some(relation.clone()),
+            ..Relate::default()
+        }).is_err());
+        assert!(Relate::build(Relate {
+            from: expr::thing("person:tobie").unwrap(),
+            edge: "wrote",
+            with: expr::thing("article:surreal").unwrap(),
+            relation: Some(Table { name: "wrote", kind: TableKind::Normal, ..Table::default() }),
+            ..Relate::default()
+        }).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        db.query("CREATE person:tobie; CREATE person:jaime; CREATE article:surreal SET draft = false; CREATE article:draft SET draft = true").await.unwrap();
+        let relation: Table = Table {
+            name: "wrote",
+            kind: TableKind::Relation,
+            relation_in: "person",
+            relation_out: "article",
+            schema_less: true,
+            ..Table::default()
+        };
+        db.query(Table::build(relation.clone()).unwrap()).await.unwrap().check().unwrap();
+        let relates: Vec<Relate> = Vec::from([
+            Relate {
+                from: Value::from(vec![expr::thing("person:tobie").unwrap(),expr::thing("person:jaime").unwrap()]),
+                edge: "wrote",
+                with: expr::value("(SELECT VALUE id FROM article WHERE draft = false)").unwrap(),
+                set: vec![Assignment::Set("source",Value::from("import"))],
+                output: Some(Return::None),
+                relation: Some(relation.clone()),
+                ..Relate::default()
+            },
+            Relate {
+                from: expr::thing("person:tobie").unwrap(),
+                edge: "likes",
+                with: expr::thing("person:jaime").unwrap(),
+                ..Relate::default()
+            }
+        ]);
+        let report = db.execute(&Query::new(relates)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let authors: Vec<String> = db.query("RETURN array::sort((SELECT VALUE meta::id(in) FROM wrote WHERE out = article:surreal AND source = 'import'))").await.unwrap().take(0).unwrap();
+        assert_eq!(authors,vec!["jaime","tobie"]);
+        let likes: Option<i64> = db.query("RETURN count(SELECT id FROM likes)").await.unwrap().take(0).unwrap();
+        assert_eq!(likes,Some(1));
+    }
+
+}
@@ -0,0 +1,216 @@
+use crate::builder::prelude::Ident;
+use crate::builder::expr;
+use crate::builder::user::UserLevel;
+use crate::builder::token::TokenLevel;
+use crate::error::Error;
+use surrealdb::sql::statements::{
+    RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement, RemoveFieldStatement,
+    RemoveFunctionStatement, RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement,
+    RemoveScopeStatement, RemoveTableStatement, RemoveTokenStatement, RemoveUserStatement
+};
+use surrealdb::sql::Base;
+
+/// The type of the schema object to remove.
+#[derive(Debug, Clone, Default)]
+pub enum RemoveKind<'a> {
+    Namespace,
+    Database,
+    #[default]
+    Table,
+    /// A field of the given table.
+    Field(&'a str),
+    /// An index of the given table.
+    Index(&'a str),
+    /// An event of the given table.
+    Event(&'a str),
+    Param,
+    Function,
+    Analyzer,
+    Scope,
+    User(UserLevel),
+    Token(TokenLevel<'a>),
+}
+
+/// # Remove
+///
+/// * `name` - The name of the schema object.
+/// * `kind` - The type of the schema object which is value of enum `RemoveKind`. Default: `RemoveKind::Table`.
+/// * `if_exists` - Only remove the schema object if it exists.
+#[derive(Debug, Clone, Default)]
+pub struct Remove<'a>{
+    pub name: &'a str,
+    pub kind: RemoveKind<'a>,
+    pub if_exists: bool,
+}
+impl <'a> Remove<'a> {
+    /// # Build a REMOVE Statement
+    /// ## Parameter
+    /// * `item` - The `Remove` struct.
+    pub fn build(
+        item: Remove
+    ) -> Result<String,Error> {
+        // Remove ======================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Remove", field: "name" });
+        }
+        let name: Ident = Ident::from(item.name.to_string());
+        let table = |table: &str| -> Result<Ident,Error> {
+            match table.is_empty() {
+                true => Err(Error::MissingName { kind: "Remove", field: "table" }),
+                false => Ok(Ident::from(table.to_string()))
+            }
+        };
+        let stmt: String = match item.kind {
+            RemoveKind::Namespace => {
+                let mut stmt: RemoveNamespaceStatement = RemoveNamespaceStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Database => {
+                let mut stmt: RemoveDatabaseStatement = RemoveDatabaseStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Table => {
+                let mut stmt: RemoveTableStatement = RemoveTableStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Field(what) => {
+                let mut stmt: RemoveFieldStatement = RemoveFieldStatement::default();
+                stmt.name = expr::idiom(item.name)?;
+                stmt.what = table(what)?;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Index(what) => {
+                let mut stmt: RemoveIndexStatement = RemoveIndexStatement::default();
+                stmt.name = name;
+                stmt.what = table(what)?;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Event(what) => {
+                let mut stmt: RemoveEventStatement = RemoveEventStatement::default();
+                stmt.name = name;
+                stmt.what = table(what)?;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Param => {
+                let mut stmt: RemoveParamStatement = RemoveParamStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Function => {
+                let mut stmt: RemoveFunctionStatement = RemoveFunctionStatement::default();
+                stmt.name = Ident::from(item.name.strip_prefix("fn::").unwrap_or(item.name).to_string());
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Analyzer => {
+                let mut stmt: RemoveAnalyzerStatement = RemoveAnalyzerStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Scope => {
+                let mut stmt: RemoveScopeStatement = RemoveScopeStatement::default();
+                stmt.name = name;
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::User(level) => {
+                let mut stmt: RemoveUserStatement = RemoveUserStatement::default();
+                stmt.name = name;
+                stmt.base = match level {
+                    UserLevel::Root => Base::Root,
+                    UserLevel::Namespace => Base::Ns,
+                    UserLevel::Database => Base::Db,
+                };
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+            RemoveKind::Token(level) => {
+                let mut stmt: RemoveTokenStatement = RemoveTokenStatement::default();
+                stmt.name = name;
+                stmt.base = match level {
+                    TokenLevel::Namespace => Base::Ns,
+                    TokenLevel::Database => Base::Db,
+                    TokenLevel::Scope(scope) => Base::Sc(table(scope)?),
+                };
+                stmt.if_exists = item.if_exists;
+                stmt.to_string()
+            },
+        };
+        Ok(stmt)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::table::Table;
+    use crate::builder::field::Field;
+    use crate::builder::index::Index;
+    use crate::builder::param::Param;
+    use crate::builder::prelude::*;
+
+    #[test]
+    fn test_builder(){
+        let removes: Vec<Remove> = Vec::from([
+            Remove { name: "tenant", kind: RemoveKind::Namespace, if_exists: true },
+            Remove { name: "app", kind: RemoveKind::Database, ..Remove::default() },
+            Remove { name: "person", kind: RemoveKind::Table, if_exists: true },
+            Remove { name: "email", kind: RemoveKind::Field("person"), ..Remove::default() },
+            Remove { name: "email_idx", kind: RemoveKind::Index("person"), ..Remove::default() },
+            Remove { name: "audit", kind: RemoveKind::Event("person"), ..Remove::default() },
+            Remove { name: "endpoint", kind: RemoveKind::Param, ..Remove::default() },
+            Remove { name: "fn::greet", kind: RemoveKind::Function, ..Remove::default() },
+            Remove { name: "english", kind: RemoveKind::Analyzer, ..Remove::default() },
+            Remove { name: "account", kind: RemoveKind::Scope, ..Remove::default() },
+            Remove { name: "service", kind: RemoveKind::User(UserLevel::Database), ..Remove::default() },
+            Remove { name: "gateway", kind: RemoveKind::Token(TokenLevel::Scope("account")), ..Remove::default() },
+        ]);
+        let stmts: String = Query::new(removes).build().unwrap();
+        assert_eq!(stmts,[
+            "REMOVE NAMESPACE IF EXISTS tenant",
+            "REMOVE DATABASE app",
+            "REMOVE TABLE IF EXISTS person",
+            "REMOVE FIELD email ON person",
+            "REMOVE INDEX email_idx ON person",
+            "REMOVE EVENT audit ON person",
+            "REMOVE PARAM $endpoint",
+            "REMOVE FUNCTION fn::greet",
+            "REMOVE ANALYZER english",
+            "REMOVE SCOPE account",
+            "REMOVE USER service ON DATABASE",
+            "REMOVE TOKEN gateway ON SCOPE account;",
+        ].join(";"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Companions of the DEFINE builders
+        let table: Table = Table { name: "person", if_not_exists: true, ..Table::default() };
+        let param_if_not_exists: Param = Param { name: "endpoint", value: Value::from("https://example.com"), if_not_exists: true, ..Param::default() };
+        let field: Field = Field { name: "email", table: "person", kind: Kind::String, ..Field::default() };
+        let index: Index = Index { name: "email_idx", table: "person", cols: vec![Idiom::from("email")], ..Index::default() };
+        let param: Param = Param { name: "endpoint", value: Value::from("https://example.com"), ..Param::default() };
+        assert_eq!(Table::remove(table,true).unwrap(),"REMOVE TABLE IF EXISTS person");
+        assert_eq!(Field::remove(field,false).unwrap(),"REMOVE FIELD email ON person");
+        assert_eq!(Field::remove(Field { name: "address.city", table: "person", ..Field::default() },true).unwrap(),"REMOVE FIELD IF EXISTS address.city ON person");
+        assert_eq!(Index::remove(index,false).unwrap(),"REMOVE INDEX email_idx ON person");
+        assert_eq!(Param::remove(param,false).unwrap(),"REMOVE PARAM $endpoint");
+        // `if_not_exists` of the definition does not carry over to the removal.
+        assert_eq!(Param::remove(param_if_not_exists,false).unwrap(),"REMOVE PARAM $endpoint");
+        // Expecting Error
+        assert!(Remove::build(Remove::default()).is_err());
+        assert!(Remove::build(Remove { name: "email", kind: RemoveKind::Field(""), ..Remove::default() }).is_err());
+        assert!(Field::remove(Field::default(),false).is_err());
+    }
+
+}
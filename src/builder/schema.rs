@@ -0,0 +1,259 @@
+use crate::builder::prelude::TableKind;
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::index::Index;
+use crate::builder::event::Event;
+use crate::builder::param::Param;
+use crate::builder::function::Function;
+use crate::builder::analyzer::Analyzer;
+use crate::builder::derive::SurrealTable;
+use crate::builder::statement::Statement;
+use crate::builder::query::Query;
+use crate::error::Error;
+
+/// # TableSchema
+///
+/// * `table` - The `Table` definition, including its permissions.
+/// * `fields` - The fields of the table. An empty `table` of a field is set to the table name.
+/// * `indexes` - The indexes of the table. An empty `table` of an index is set to the table name.
+/// * `events` - The events of the table. An empty `table` of an event is set to the table name.
+#[derive(Debug, Clone, Default)]
+pub struct TableSchema<'a>{
+    pub table: Table<'a>,
+    pub fields: Vec<Field<'a>>,
+    pub indexes: Vec<Index<'a>>,
+    pub events: Vec<Event<'a>>,
+}
+
+impl TableSchema<'static> {
+    /// # The TableSchema of a record struct
+    /// See: `SurrealTable`.
+    pub fn of<T: SurrealTable>() -> Result<Self,Error> {
+        Ok(Self {
+            table: T::table()?,
+            fields: T::fields()?,
+            indexes: T::indexes()?,
+            events: Vec::new(),
+        })
+    }
+}
+
+/// # Schema
+///
+/// * `params` - The database parameters.
+/// * `functions` - The database functions.
+/// * `analyzers` - The analyzers of the search indexes.
+/// * `tables` - The tables with their fields, indexes and events.
+#[derive(Debug, Clone, Default)]
+pub struct Schema<'a>{
+    pub params: Vec<Param<'a>>,
+    pub functions: Vec<Function<'a>>,
+    pub analyzers: Vec<Analyzer<'a>>,
+    pub tables: Vec<TableSchema<'a>>,
+}
+
+impl <'a> Schema<'a> {
+    /// # Build the Statements of a Schema in Dependency Order
+    /// Params, functions and analyzers come first, analyzers may use functions and search indexes use analyzers.
+    /// Then the tables, a relation table after its `in` and `out` tables and a view after the tables it selects from.
+    /// Then the fields, the indexes and the events of every table in the order of the tables.
+    /// ## Parameter
+    /// * `item` - The `Schema` struct.
+    pub fn statements(
+        item: Schema<'a>
+    ) -> Result<Vec<Box<dyn Statement + 'a>>,Error> {
+        // Schema ======================================================
+        let item: Schema<'a> = Schema::resolve(item)?;
+        let mut stmts: Vec<Box<dyn Statement + 'a>> = Vec::new();
+        stmts.extend(item.params.into_iter().map(|param| Box::new(param) as Box<dyn Statement + 'a>));
+        stmts.extend(item.functions.into_iter().map(|function| Box::new(function) as Box<dyn Statement + 'a>));
+        stmts.extend(item.analyzers.into_iter().map(|analyzer| Box::new(analyzer) as Box<dyn Statement + 'a>));
+        stmts.extend(item.tables.iter().map(|table| Box::new(table.table.clone()) as Box<dyn Statement + 'a>));
+        for table in item.tables {
+            stmts.extend(table.fields.into_iter().map(|field| Box::new(field) as Box<dyn Statement + 'a>));
+            stmts.extend(table.indexes.into_iter().map(|index| Box::new(index) as Box<dyn Statement + 'a>));
+            stmts.extend(table.events.into_iter().map(|event| Box::new(event) as Box<dyn Statement + 'a>));
+        }
+        Ok(stmts)
+    }
+
+    /// # Build the Script of a Schema
+    /// See: `Schema::statements`.
+    /// ## Parameter
+    /// * `item` - The `Schema` struct.
+    pub fn build(
+        item: Schema
+    ) -> Result<String,Error> {
+        Query::new(Schema::statements(item)?).build()
+    }
+
+    /// Validate the schema, set the table of the fields, indexes and events and order the tables after their dependencies.
+    pub(crate) fn resolve(item: Schema<'a>) -> Result<Schema<'a>,Error> {
+        let mut tables: Vec<TableSchema<'a>> = Vec::with_capacity(item.tables.len());
+        for mut schema in item.tables {
+            let name: &'a str = schema.table.name;
+            if name.is_empty() {
+                return Err(Error::MissingName { kind: "Table", field: "name" });
+            }
+            if tables.iter().any(|table| table.table.name == name) {
+                return Err(Error::invalid("Schema",format!("Table {} is defined more than once.",name)));
+            }
+            for table in schema.fields.iter_mut().map(|field| &mut field.table)
+                .chain(schema.indexes.iter_mut().map(|index| &mut index.table))
+                .chain(schema.events.iter_mut().map(|event| &mut event.table)) {
+                if table.is_empty() {
+                    *table = name;
+                }
+                if *table != name {
+                    return Err(Error::invalid("Schema",format!("Table {} holds a definition of table {}.",name,table)));
+                }
+            }
+            tables.push(schema);
+        }
+        let indexes: Vec<Index<'a>> = tables.iter().flat_map(|table| table.indexes.iter().cloned()).collect();
+        Analyzer::validate(&item.analyzers, &indexes)?;
+        Ok(Schema {
+            tables: Schema::order(tables)?,
+            ..item
+        })
+    }
+
+    /// The tables which have to be defined before the table.
+    fn dependencies(table: &Table<'a>) -> Vec<String> {
+        let mut dependencies: Vec<String> = Vec::new();
+        if matches!(table.kind, TableKind::Relation) {
+            dependencies.extend([table.relation_in, table.relation_out].iter()
+                .filter(|name| !name.is_empty())
+                .map(|name| name.to_string()));
+        }
+        if let Some(view) = &table.view {
+            dependencies.extend(view.what.0.iter().map(|what| what.0.clone()));
+        }
+        dependencies.retain(|name| name != table.name);
+        dependencies
+    }
+
+    /// Order the tables after their dependencies, keeping the given order otherwise.
+    fn order(mut tables: Vec<TableSchema<'a>>) -> Result<Vec<TableSchema<'a>>,Error> {
+        for table in tables.iter() {
+            for dependency in Schema::dependencies(&table.table) {
+                if !tables.iter().any(|table| table.table.name == dependency) {
+                    return Err(Error::UnknownTable(dependency));
+                }
+            }
+        }
+        let mut ordered: Vec<TableSchema<'a>> = Vec::with_capacity(tables.len());
+        while !tables.is_empty() {
+            let next: Option<usize> = tables.iter().position(|table| {
+                Schema::dependencies(&table.table).iter().all(|dependency| {
+                    ordered.iter().any(|table| table.table.name == dependency)
+                })
+            });
+            match next {
+                Some(index) => ordered.push(tables.remove(index)),
+                None => {
+                    let names: Vec<&str> = tables.iter().map(|table| table.table.name).collect();
+                    return Err(Error::invalid("Schema",format!("Tables {} depend on each other.",names.join(", "))));
+                }
+            }
+        }
+        Ok(ordered)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::prelude::*;
+    use crate::db::Db;
+
+    #[tokio::test]
+    async fn test_builder(){
+        let schema: Schema = Schema {
+            params: vec![Param { name: "max_likes", value: Value::from(100), ..Param::default() }],
+            functions: vec![Function {
+                name: "fn::normalize",
+                args: vec![(Ident::from("text"),Kind::String)],
+                block: Function::block("{ RETURN string::lowercase($text); }").unwrap(),
+                ..Function::default()
+            }],
+            analyzers: vec![Analyzer {
+                name: "english",
+                tokenizers: vec![Tokenizer::Blank],
+                filters: vec![Filter::Lowercase],
+                ..Analyzer::default()
+            }],
+            tables: vec![
+                TableSchema {
+                    table: Table { name: "likes", kind: TableKind::Relation, relation_in: "person", relation_out: "article", ..Table::default() },
+                    fields: vec![Field { name: "time", kind: Kind::Datetime, ..Field::default() }],
+                    ..TableSchema::default()
+                },
+                TableSchema {
+                    table: Table { name: "article", kind: TableKind::Normal, ..Table::default() },
+                    fields: vec![Field { name: "body", kind: Kind::String, ..Field::default() }],
+                    indexes: vec![Index {
+                        name: "body_search",
+                        cols: vec![Idiom::from("body")],
+                        index: surrealdb::sql::parse("DEFINE INDEX body_search ON article FIELDS body SEARCH ANALYZER english BM25").map(|query| match query.0.0.into_iter().next() {
+                            Some(surrealdb::sql::Statement::Define(surrealdb::sql::statements::DefineStatement::Index(define))) => define.index,
+                            _ => IndexType::Idx,
+                        }).unwrap(),
+                        ..Index::default()
+                    }],
+                    events: vec![Event {
+                        name: "article_created",
+                        when: Event::on(&[EventKind::Create]),
+                        then: vec![Event::then("CREATE log SET article = $after.id").unwrap()],
+                        ..Event::default()
+                    }],
+                },
+                TableSchema {
+                    table: Table { name: "person", kind: TableKind::Normal, ..Table::default() },
+                    fields: vec![Field { name: "name", kind: Kind::String, ..Field::default() }],
+                    ..TableSchema::default()
+                },
+            ],
+        };
+        let stmts: String = Schema::build(schema.clone()).unwrap();
+        let order: Vec<usize> = [
+            "DEFINE PARAM $max_likes",
+            "DEFINE FUNCTION fn::normalize",
+            "DEFINE ANALYZER english",
+            "DEFINE TABLE article",
+            "DEFINE TABLE person",
+            "DEFINE TABLE likes TYPE RELATION IN person OUT article",
+            "DEFINE FIELD body ON article",
+            "DEFINE INDEX body_search ON article",
+            "DEFINE EVENT article_created ON article",
+            "DEFINE FIELD name ON person",
+            "DEFINE FIELD time ON likes",
+        ].iter().map(|stmt| stmts.find(stmt).unwrap_or_else(|| panic!("Missing {} in {}",stmt,stmts))).collect();
+        assert!(order.windows(2).all(|pair| pair[0] < pair[1]),"Unexpected order: {}",stmts);
+        let db = Db::memory("test","test").await.unwrap();
+        let report = db.execute(&Query::new(Schema::statements(schema.clone()).unwrap())).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        // Expecting Error
+        let mut unknown: Schema = schema.clone();
+        unknown.tables.remove(2);
+        assert_eq!(Schema::build(unknown).unwrap_err(),Error::UnknownTable("person".to_string()));
+        let mut analyzer: Schema = schema.clone();
+        analyzer.analyzers.clear();
+        assert_eq!(Schema::build(analyzer).unwrap_err(),Error::UnknownAnalyzer("english".to_string()));
+        let mut cycle: Schema = schema.clone();
+        cycle.tables[2].table = Table {
+            name: "person",
+            view: match surrealdb::sql::parse("DEFINE TABLE person AS SELECT count() FROM likes GROUP ALL").unwrap().0.0.into_iter().next() {
+                Some(surrealdb::sql::Statement::Define(surrealdb::sql::statements::DefineStatement::Table(define))) => define.view,
+                _ => None,
+            },
+            ..Table::default()
+        };
+        assert!(matches!(Schema::build(cycle), Err(Error::Invalid { kind: "Schema", .. })));
+        let mut mismatch: Schema = schema.clone();
+        mismatch.tables[2].fields[0].table = "article";
+        assert!(matches!(Schema::build(mismatch), Err(Error::Invalid { kind: "Schema", .. })));
+    }
+
+}
@@ -0,0 +1,120 @@
+use crate::builder::prelude::{ Ident, Strand, Value };
+use crate::builder::table::Table;
+use crate::error::Error;
+use surrealdb::sql::statements::DefineScopeStatement;
+/// # Scope
+///
+/// * `name` - The scope name.
+/// * `session` - The duration in seconds of an authenticated session. `0` uses the server default.
+/// * `signup` - The query executed when a user signs up. Use `Scope::query` to parse it from a string.
+/// * `signin` - The query executed when a user signs in. Use `Scope::query` to parse it from a string.
+/// * `comment` - Comment on the scope.
+/// * `if_not_exists` - Create or define the scope if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Scope<'a>{
+    pub name: &'a str,
+    pub session: u64,
+    pub signup: Option<Value>,
+    pub signin: Option<Value>,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+}
+impl <'a> Scope<'a> {
+    /// # Build a DEFINE SCOPE Statement
+    /// ## Parameter
+    /// * `item` - The `Scope` struct.
+    pub fn build(
+        item: Scope
+    ) -> Result<String,Error> {
+        // Scope =======================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Scope", field: "name" });
+        }
+        let mut stmt: DefineScopeStatement = DefineScopeStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.signup         = item.signup;
+        stmt.signin         = item.signin;
+        stmt.if_not_exists  = item.if_not_exists;
+        if item.session > 0 {
+            stmt.session = Some(surrealdb::sql::Duration::from(core::time::Duration::from_secs(item.session)));
+        }
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+
+    /// # Parse a SIGNUP or SIGNIN query
+    /// ## Parameter
+    /// * `query` - The SurrealQL query, e.g. `SELECT * FROM user WHERE email = $email`.
+    pub fn query(query: &str) -> Result<Value,Error> {
+        surrealdb::sql::value(query).map_err(|error| Error::identifier("scope query",query,error))
+    }
+
+    /// # Email and password scope
+    /// Users sign up with `$email` and `$pass`. The password is stored in the `pass`
+    /// field hashed with `crypto::argon2` and the email in the `email` field of the table.
+    /// ## Parameters
+    /// * `name` - The scope name.
+    /// * `table` - The `Table` which stores the users.
+    /// * `session` - The duration in seconds of an authenticated session.
+    pub fn email_password(
+        name: &'a str,
+        table: &Table,
+        session: u64
+    ) -> Result<Scope<'a>,Error> {
+        if table.name.is_empty() {
+            return Err(Error::MissingName { kind: "Table", field: "name" });
+        }
+        let table: Ident = Ident::from(table.name.to_string());
+        Ok(Scope {
+            name,
+            session,
+            signup: Some(Scope::query(
+                format!("CREATE {} SET email = $email, pass = crypto::argon2::generate($pass)",table).as_str()
+            )?),
+            signin: Some(Scope::query(
+                format!("SELECT * FROM {} WHERE email = $email AND crypto::argon2::compare(pass, $pass)",table).as_str()
+            )?),
+            ..Scope::default()
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::prelude::*;
+
+    #[test]
+    fn test_builder(){
+        let table: Table = Table {
+            name: "account",
+            kind: TableKind::Normal,
+            permissions: Permissions::full(),
+            ..Table::default()
+        };
+        let scopes: Vec<Scope> = Vec::from([
+            Scope::email_password("account_scope",&table,86400).unwrap(),
+            Scope {
+                name: "guest",
+                session: 900,
+                signin: Some(Scope::query("SELECT * FROM guest WHERE token = $token").unwrap()),
+                comment: "guest access",
+                if_not_exists: true,
+                ..Scope::default()
+            }
+        ]);
+        let stmts: String = Query::new(scopes).build().unwrap();
+        assert!(stmts.contains("DEFINE SCOPE account_scope SESSION 1d SIGNUP (CREATE account SET email = $email, pass = crypto::argon2::generate($pass))"));
+        assert!(stmts.contains("SIGNIN (SELECT * FROM account WHERE email = $email AND crypto::argon2::compare(pass, $pass))"));
+        assert!(stmts.contains("DEFINE SCOPE IF NOT EXISTS guest SESSION 15m SIGNIN (SELECT * FROM guest WHERE token = $token) COMMENT 'guest access'"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Scope::build(Scope::default()).is_err());
+        assert!(Scope::email_password("no_table",&Table::default(),3600).is_err());
+    }
+
+}
@@ -0,0 +1,289 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr;
+use crate::error::Error;
+use surrealdb::sql::statements::SelectStatement;
+use surrealdb::sql::{
+    Explain, Fetch, Fetchs, Fields, Group, Groups, Limit, Order, Orders, Split, Splits,
+    Start
+};
+
+/// A projected field of a SELECT statement.
+#[derive(Debug, Clone)]
+pub enum Projection<'a> {
+    /// `*`
+    All,
+    /// A field path, e.g. `profile.email`.
+    Field(&'a str),
+    /// An expression with an alias, e.g. `count() AS total`.
+    Alias(Value, &'a str),
+}
+
+/// # OrderBy
+///
+/// * `field` - The field path to order by.
+/// * `descending` - Order from the highest to the lowest value.
+/// * `collate` - Order text using unicode collation.
+/// * `numeric` - Order text containing numbers numerically.
+#[derive(Debug, Clone, Default)]
+pub struct OrderBy<'a>{
+    pub field: &'a str,
+    pub descending: bool,
+    pub collate: bool,
+    pub numeric: bool,
+}
+
+/// # Select
+///
+/// * `fields` - The projection. Default: `*`.
+/// * `value` - Return only the value of the single projected field (`SELECT VALUE`).
+/// * `only` - Return a single record instead of an array.
+/// * `from` - The tables or record ids to select from. See: `expr::table`, `expr::thing`.
+/// * `cond` - The `WHERE` condition. See: `expr::value`.
+/// * `split` - The fields to split the results on.
+/// * `group` - The fields to group the results by.
+/// * `group_all` - Group all the results into a single record (`GROUP ALL`).
+/// * `order` - The ordering of the results.
+/// * `limit` - The maximum number of results.
+/// * `start` - The number of results to skip.
+/// * `fetch` - The record links to fetch.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Fetch the records in parallel.
+/// * `explain` - Return the execution plan instead of the results.
+/// * `explain_full` - Only applicable if `explain` is `true`. Include the number of fetched records.
+#[derive(Debug, Clone, Default)]
+pub struct Select<'a>{
+    pub fields: Vec<Projection<'a>>,
+    pub value: bool,
+    pub only: bool,
+    pub from: Vec<Value>,
+    pub cond: Option<Value>,
+    pub split: Vec<&'a str>,
+    pub group: Vec<&'a str>,
+    pub group_all: bool,
+    pub order: Vec<OrderBy<'a>>,
+    pub limit: Option<u64>,
+    pub start: Option<u64>,
+    pub fetch: Vec<&'a str>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+    pub explain: bool,
+    pub explain_full: bool,
+}
+impl <'a> Select<'a> {
+    /// # Build a SELECT Statement
+    /// ## Parameter
+    /// * `item` - The `Select` struct.
+    pub fn build(
+        item: Select
+    ) -> Result<String,Error> {
+        // Select ======================================================
+        if item.from.is_empty() {
+            return Err(Error::invalid("Select","Select requires at least one table or record id."));
+        }
+        if item.value && item.fields.len() != 1 {
+            return Err(Error::invalid("Select","Select VALUE requires exactly one field."));
+        }
+        if item.group_all && !item.group.is_empty() {
+            return Err(Error::invalid("Select","Select cannot use both GROUP BY and GROUP ALL."));
+        }
+        if !item.fields.is_empty() && !item.fields.iter().any(|field| matches!(field, Projection::All)) {
+            // SurrealDB requires ordered, split and grouped fields to be projected.
+            let projected: Vec<&str> = item.fields.iter().map(|field| match field {
+                Projection::All => "*",
+                Projection::Field(path) => path,
+                Projection::Alias(_, alias) => alias,
+            }).collect();
+            let paths = item.order.iter().map(|order_by| order_by.field)
+                .chain(item.split.iter().copied())
+                .chain(item.group.iter().copied());
+            for path in paths {
+                if !projected.contains(&path) {
+                    return Err(Error::invalid("Select",format!("Select field {} is not present in the projection.",path)));
+                }
+            }
+        }
+        let mut stmt: SelectStatement = SelectStatement::default();
+        let mut fields: Fields = Fields::all();
+        if !item.fields.is_empty() {
+            fields.0 = Vec::new();
+            for field in item.fields {
+                fields.0.push(match field {
+                    Projection::All => surrealdb::sql::Field::All,
+                    Projection::Field(path) => surrealdb::sql::Field::Single {
+                        expr: Value::Idiom(expr::idiom(path)?),
+                        alias: None
+                    },
+                    Projection::Alias(value, alias) => surrealdb::sql::Field::Single {
+                        expr: value,
+                        alias: Some(expr::idiom(alias)?)
+                    },
+                });
+            }
+            fields.1 = item.value;
+        }
+        stmt.expr       = fields;
+        stmt.only       = item.only;
+        stmt.what       = expr::values(item.from);
+        stmt.parallel   = item.parallel;
+        if let Some(value) = item.cond {
+            stmt.cond = Some(expr::cond(value));
+        }
+        if !item.split.is_empty() {
+            let mut splits: Splits = Splits::default();
+            for path in item.split {
+                let mut split: Split = Split::default();
+                split.0 = expr::idiom(path)?;
+                splits.0.push(split);
+            }
+            stmt.split = Some(splits);
+        }
+        if item.group_all || !item.group.is_empty() {
+            let mut groups: Groups = Groups::default();
+            for path in item.group {
+                let mut group: Group = Group::default();
+                group.0 = expr::idiom(path)?;
+                groups.0.push(group);
+            }
+            stmt.group = Some(groups);
+        }
+        if !item.order.is_empty() {
+            let mut orders: Orders = Orders::default();
+            for order_by in item.order {
+                let mut order: Order = Order::default();
+                order.order     = expr::idiom(order_by.field)?;
+                order.collate   = order_by.collate;
+                order.numeric   = order_by.numeric;
+                order.direction = !order_by.descending;
+                orders.0.push(order);
+            }
+            stmt.order = Some(orders);
+        }
+        if let Some(value) = item.limit {
+            let mut limit: Limit = Limit::default();
+            limit.0 = Value::from(value);
+            stmt.limit = Some(limit);
+        }
+        if let Some(value) = item.start {
+            let mut start: Start = Start::default();
+            start.0 = Value::from(value);
+            stmt.start = Some(start);
+        }
+        if !item.fetch.is_empty() {
+            let mut fetchs: Fetchs = Fetchs::default();
+            for path in item.fetch {
+                let mut fetch: Fetch = Fetch::default();
+                fetch.0 = expr::idiom(path)?;
+                fetchs.0.push(fetch);
+            }
+            stmt.fetch = Some(fetchs);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        if item.explain {
+            let mut explain: Explain = Explain::default();
+            explain.0 = item.explain_full;
+            stmt.explain = Some(explain);
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Db;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let stmt: String = Select::build(Select {
+            fields: vec![
+                Projection::Field("name"),
+                Projection::Field("profile.email"),
+                Projection::Field("age"),
+                Projection::Alias(expr::value("math::sum(orders.total)").unwrap(),"spent"),
+            ],
+            from: vec![expr::table("user")],
+            cond: Some(expr::value("age >= 18 AND active = true").unwrap()),
+            order: vec![
+                OrderBy { field: "name", collate: true, ..OrderBy::default() },
+                OrderBy { field: "age", numeric: true, descending: true, ..OrderBy::default() },
+            ],
+            limit: Some(50),
+            start: Some(100),
+            fetch: vec!["orders"],
+            timeout: Some(core::time::Duration::from_secs(5)),
+            parallel: true,
+            ..Select::default()
+        }).unwrap();
+        assert_eq!(stmt,"SELECT name, profile.email, age, math::sum(orders.total) AS spent FROM user WHERE age >= 18 AND active = true ORDER BY name COLLATE, age NUMERIC DESC LIMIT 50 START 100 FETCH orders TIMEOUT 5s PARALLEL");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        let stmt: String = Select::build(Select {
+            fields: vec![Projection::Alias(expr::value("count()").unwrap(),"total"),Projection::Field("country"),Projection::Field("emails")],
+            from: vec![expr::table("user"),expr::thing("admin:root").unwrap()],
+            group: vec!["country","emails"],
+            split: vec!["emails"],
+            explain: true,
+            explain_full: true,
+            ..Select::default()
+        }).unwrap();
+        assert_eq!(stmt,"SELECT count() AS total, country, emails FROM user, admin:root SPLIT ON emails GROUP BY country, emails EXPLAIN FULL");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        let stmt: String = Select::build(Select {
+            fields: vec![Projection::Field("email")],
+            value: true,
+            only: true,
+            from: vec![expr::thing("user:john").unwrap()],
+            ..Select::default()
+        }).unwrap();
+        assert_eq!(stmt,"SELECT VALUE email FROM ONLY user:john");
+        let stmt: String = Select::build(Select {
+            fields: vec![Projection::Alias(expr::value("count()").unwrap(),"total")],
+            from: vec![expr::table("user")],
+            group_all: true,
+            ..Select::default()
+        }).unwrap();
+        assert_eq!(stmt,"SELECT count() AS total FROM user GROUP ALL");
+        // Expecting Error
+        assert!(Select::build(Select::default()).is_err());
+        assert!(Select::build(Select { from: vec![expr::table("user")], value: true, ..Select::default() }).is_err());
+        assert!(Select::build(Select { from: vec![expr::table("user")], fields: vec![Projection::Field("a b")], ..Select::default() }).is_err());
+        assert!(Select::build(Select {
+            from: vec![expr::table("user")],
+            fields: vec![Projection::Field("name")],
+            order: vec![OrderBy { field: "age", ..OrderBy::default() }],
+            ..Select::default()
+        }).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        db.query("CREATE user:john SET name = 'John', age = 30, country = 'US'; CREATE user:jane SET name = 'Jane', age = 17, country = 'UK'; CREATE user:joe SET name = 'Joe', age = 45, country = 'US'").await.unwrap();
+        let selects: Vec<Select> = Vec::from([
+            Select {
+                fields: vec![Projection::Field("name"),Projection::Field("age")],
+                from: vec![expr::table("user")],
+                cond: Some(expr::value("age >= 18").unwrap()),
+                order: vec![OrderBy { field: "age", numeric: true, descending: true, ..OrderBy::default() }],
+                limit: Some(1),
+                ..Select::default()
+            },
+            Select {
+                fields: vec![Projection::Alias(expr::value("count()").unwrap(),"total"),Projection::Field("country")],
+                from: vec![expr::table("user")],
+                group: vec!["country"],
+                ..Select::default()
+            }
+        ]);
+        let mut report = db.execute(&Query::new(selects)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let oldest: Vec<String> = report.response.take((0,"name")).unwrap();
+        assert_eq!(oldest,vec!["Joe"]);
+        let totals: Vec<i64> = report.response.take((1,"total")).unwrap();
+        assert_eq!(totals,vec![1,2]);
+    }
+
+}
@@ -0,0 +1,232 @@
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::index::Index;
+use crate::builder::param::Param;
+use crate::builder::function::Function;
+use crate::builder::event::Event;
+use crate::builder::analyzer::Analyzer;
+use crate::builder::namespace::Namespace;
+use crate::builder::database::Database;
+use crate::builder::user::User;
+use crate::builder::token::Token;
+use crate::builder::scope::Scope;
+use crate::builder::select::Select;
+use crate::builder::create::Create;
+use crate::builder::insert::Insert;
+use crate::builder::update::Update;
+use crate::builder::delete::Delete;
+use crate::builder::relate::Relate;
+use crate::builder::upsert::Upsert;
+use crate::builder::remove::Remove;
+use crate::builder::transaction::Transaction;
+use crate::builder::schema::Schema;
+use crate::builder::diff::Change;
+use crate::error::Error;
+
+/// A builder which renders a statement, so builders of different types can be built in one `Query`.
+///
+/// ```ignore
+/// let items: Vec<Box<dyn Statement>> = vec![
+///     Box::new(Table { name: "person", ..Table::default() }),
+///     Box::new(Field { name: "email", table: "person", kind: Kind::String, ..Field::default() }),
+///     Box::new(Index { name: "email_idx", table: "person", cols: vec![Idiom::from("email")], index: IndexType::Uniq, ..Index::default() }),
+/// ];
+/// let stmts: String = Query::new(items).build()?;
+/// ```
+pub trait Statement: std::fmt::Debug {
+    /// Build the statement of the builder.
+    fn statement(&self) -> Result<String,Error>;
+}
+
+impl Statement for Box<dyn Statement + '_> {
+    fn statement(&self) -> Result<String,Error> {
+        self.as_ref().statement()
+    }
+}
+
+impl Statement for Table<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Table::build(self.clone())
+    }
+}
+
+impl Statement for Field<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Field::build(self.clone())
+    }
+}
+
+impl Statement for Index<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Index::build(self.clone())
+    }
+}
+
+impl Statement for Param<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Param::build(self.clone())
+    }
+}
+
+impl Statement for Function<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Function::build(self.clone())
+    }
+}
+
+impl Statement for Event<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Event::build(self.clone())
+    }
+}
+
+impl Statement for Analyzer<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Analyzer::build(self.clone())
+    }
+}
+
+impl Statement for Namespace<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Namespace::build(self.clone())
+    }
+}
+
+impl Statement for Database<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Database::build(self.clone())
+    }
+}
+
+impl Statement for User<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        User::build(self.clone())
+    }
+}
+
+impl Statement for Token<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Token::build(self.clone())
+    }
+}
+
+impl Statement for Scope<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Scope::build(self.clone())
+    }
+}
+
+impl Statement for Select<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Select::build(self.clone())
+    }
+}
+
+impl Statement for Create<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Create::build(self.clone())
+    }
+}
+
+impl Statement for Insert<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Insert::build(self.clone())
+    }
+}
+
+impl Statement for Update<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Update::build(self.clone())
+    }
+}
+
+impl Statement for Delete<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Delete::build(self.clone())
+    }
+}
+
+impl Statement for Relate<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Relate::build(self.clone())
+    }
+}
+
+impl Statement for Upsert<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Upsert::build(self.clone())
+    }
+}
+
+impl Statement for Remove<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Remove::build(self.clone())
+    }
+}
+
+impl Statement for Schema<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Ok(Schema::build(self.clone())?.trim_end_matches(';').to_string())
+    }
+}
+
+impl Statement for Change {
+    fn statement(&self) -> Result<String,Error> {
+        Ok(self.statement.clone())
+    }
+}
+
+impl Statement for Transaction<'_> {
+    fn statement(&self) -> Result<String,Error> {
+        Ok(Transaction::build(self.clone())?.trim_end_matches(';').to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::prelude::*;
+    use crate::builder::expr;
+
+    #[test]
+    fn test_builder(){
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "person", kind: TableKind::Normal, ..Table::default() }),
+            Box::new(Field { name: "email", table: "person", kind: Kind::String, ..Field::default() }),
+            Box::new(Index { name: "email_idx", table: "person", cols: vec![Idiom::from("email")], index: IndexType::Uniq, ..Index::default() }),
+            Box::new(Param { name: "domain", value: Value::from("surrealdb.com"), ..Param::default() }),
+            Box::new(Transaction {
+                stmts: vec![Delete { what: vec![expr::table("person")], unbounded: true, ..Delete::default() }.statement().unwrap()],
+                ..Transaction::default()
+            }),
+            Box::new(Remove { name: "domain", kind: RemoveKind::Param, ..Remove::default() }),
+        ];
+        let mut query: Query<Box<dyn Statement>> = Query::new(items);
+        let stmts: String = query.build().unwrap();
+        assert_eq!(query.build().unwrap(),stmts);
+        assert_eq!(stmts,[
+            "DEFINE TABLE person TYPE NORMAL SCHEMAFULL PERMISSIONS FULL",
+            "DEFINE FIELD email ON person TYPE string PERMISSIONS FULL",
+            "DEFINE INDEX email_idx ON person FIELDS email UNIQUE",
+            "DEFINE PARAM $domain VALUE 'surrealdb.com' PERMISSIONS FULL",
+            "BEGIN TRANSACTION",
+            "DELETE person",
+            "COMMIT TRANSACTION",
+            "REMOVE PARAM $domain;",
+        ].join(";"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "person", ..Table::default() }),
+            Box::new(Field { table: "person", ..Field::default() }),
+        ];
+        assert_eq!(Query::new(items).build().unwrap_err().index(),Some(1));
+        let mut query: Query<Box<dyn Statement>> = Query::new(vec![Box::new(Table { name: "person", ..Table::default() })]);
+        assert!(query.build().is_ok());
+        query.items.push(Box::new(Field { table: "person", ..Field::default() }));
+        assert_eq!(query.build().unwrap_err().index(),Some(1));
+    }
+
+}
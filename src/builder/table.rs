@@ -1,21 +1,18 @@
 use crate::builder::prelude::{Permissions, Ident, View, ChangeFeed, Strand, Kind};
+use crate::builder::remove::{ Remove, RemoveKind };
+use crate::error::Error;
 use surrealdb::sql::statements::DefineTableStatement;
 use surrealdb::sql::TableType;
 use surrealdb::sql::Relation;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub enum TableKind {
+    #[default]
     Any,
     Normal,
     Relation
 }
 
-impl Default for TableKind {
-    fn default() -> Self {
-        TableKind::Any
-    }
-}
-
 /// # Table
 /// 
 /// * `name` - The table name.
@@ -51,10 +48,10 @@ impl <'a> Table<'a> {
     /// * `table` - The `Table` struct.
     pub fn build(
         item: Table
-    ) -> Result<String,String> {        
+    ) -> Result<String,Error> {        
 
-        if item.name.len() == 0 {
-            return Err("Table name is required.".to_string());
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Table", field: "name" });
         }
         
         let mut stmt: DefineTableStatement = DefineTableStatement::default();        
@@ -66,7 +63,7 @@ impl <'a> Table<'a> {
             TableKind::Normal => TableType::Normal,
             TableKind::Relation => {                
                 let mut relation: Relation = Relation::default();
-                if item.relation_in.len() > 0 {
+                if !item.relation_in.is_empty() {
                     relation.from = Some(
                         Kind::Record(
                             vec![
@@ -75,7 +72,7 @@ impl <'a> Table<'a> {
                         )
                     );
                 }
-                if item.relation_out.len() > 0 {
+                if !item.relation_out.is_empty() {
                     relation.to = Some(
                         Kind::Record(
                             vec![
@@ -90,7 +87,7 @@ impl <'a> Table<'a> {
         stmt.view = item.view;
         stmt.permissions = item.permissions;
         stmt.if_not_exists = item.if_not_exists;
-        if item.comment.len() > 0 {
+        if !item.comment.is_empty() {
             stmt.comment = Some(Strand::from(item.comment));
         }
         if item.changefeed > 0 {            
@@ -101,6 +98,22 @@ impl <'a> Table<'a> {
         }
         Ok(stmt.to_string())
     }
+
+    /// # Build the REMOVE TABLE Statement of a `Table`
+    /// The inverse of `Table::build`.
+    /// ## Parameters
+    /// * `item` - The `Table` struct.
+    /// * `if_exists` - Do not fail when the table does not exist.
+    pub fn remove(
+        item: Table,
+        if_exists: bool
+    ) -> Result<String,Error> {
+        Remove::build(Remove {
+            name: item.name,
+            kind: RemoveKind::Table,
+            if_exists,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -112,7 +125,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_table(){
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         // Expecting Success
         match Table::build(Table {       
             name: "test_table_define",
@@ -147,7 +160,7 @@ mod tests {
             comment: "My comment",
             ..Table::default()  
         }) {
-            Err(error) => assert!(error.len() > 0),
+            Err(error) => assert!(matches!(error, Error::MissingName { kind: "Table", field: "name" })),
             Ok(stmt) => {
                 assert!(false,"Query should {} failed.",stmt);
             },
@@ -216,7 +229,7 @@ mod tests {
                 schema_less: true,
                 changefeed: 3000,
                 include_original: false,
-                permissions: permissions,
+                permissions,
                 comment: "My comment",
                 relation_in: "test_table_in",
                 relation_out: "test_table_out",
@@ -0,0 +1,138 @@
+use crate::builder::prelude::{ Ident, Strand, Algorithm };
+use crate::error::Error;
+use surrealdb::sql::statements::DefineTokenStatement;
+use surrealdb::sql::Base;
+
+/// The level on which the token is verified.
+#[derive(Debug, Clone, Default)]
+pub enum TokenLevel<'a> {
+    Namespace,
+    #[default]
+    Database,
+    Scope(&'a str)
+}
+
+/// # Token
+///
+/// * `name` - The token name.
+/// * `level` - The level of the token which is value of enum `TokenLevel`. Default: `TokenLevel::Database`.
+/// * `algorithm` - The signing algorithm of the JWT. See: <https://surrealdb.com/docs/surrealdb/surrealql/statements/define/token>
+/// * `key` - The secret or public key which verifies the JWT signature.
+/// * `jwks_url` - The URL of a JSON Web Key Set. Only one of `key` or `jwks_url` can be set. The `algorithm` is ignored. Requires the `jwks` feature.
+/// * `comment` - Comment on the token.
+/// * `if_not_exists` - Create or define the token if not exists.
+#[derive(Debug, Clone, Default)]
+pub struct Token<'a>{
+    pub name: &'a str,
+    pub level: TokenLevel<'a>,
+    pub algorithm: Algorithm,
+    pub key: &'a str,
+    pub jwks_url: &'a str,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+}
+impl <'a> Token<'a> {
+    /// # Build a DEFINE TOKEN Statement
+    /// ## Parameter
+    /// * `item` - The `Token` struct.
+    pub fn build(
+        item: Token
+    ) -> Result<String,Error> {
+        // Token =======================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "Token", field: "name" });
+        }
+        if item.key.is_empty() == item.jwks_url.is_empty() {
+            return Err(Error::invalid("Token","Token requires either a key or a JWKS url."));
+        }
+        #[cfg(not(feature = "jwks"))]
+        if !item.jwks_url.is_empty() {
+            return Err(Error::invalid("Token","Token JWKS url requires the `jwks` feature."));
+        }
+        let mut stmt: DefineTokenStatement = DefineTokenStatement::default();
+        stmt.name           = Ident::from(item.name.to_string());
+        stmt.base           = match item.level {
+            TokenLevel::Namespace => Base::Ns,
+            TokenLevel::Database => Base::Db,
+            TokenLevel::Scope(scope) => {
+                if scope.is_empty() {
+                    return Err(Error::MissingName { kind: "Token", field: "scope" });
+                }
+                Base::Sc(Ident::from(scope.to_string()))
+            }
+        };
+        if item.jwks_url.is_empty() {
+            stmt.kind       = item.algorithm;
+            stmt.code       = item.key.to_string();
+        } else {
+            stmt.kind       = Algorithm::Jwks;
+            stmt.code       = item.jwks_url.to_string();
+        }
+        stmt.if_not_exists  = item.if_not_exists;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let tokens: Vec<Token> = Vec::from([
+            Token {
+                name: "gateway",
+                level: TokenLevel::Database,
+                algorithm: Algorithm::Hs512,
+                key: "sNSYneezcr8kqphfOC6NwwraUHJCVAt0XjsRSNmssBaBRh3WyMa9TRfq8ST7fsU2H2kGiOpU4GbAF1bCiXmM1b3JGgleBzz7rsrz6VvYEM4q3CLkcO8CMBIlhwhzWmy8",
+                comment: "api gateway",
+                if_not_exists: true,
+                ..Token::default()
+            },
+            Token {
+                name: "gateway_rsa",
+                level: TokenLevel::Namespace,
+                algorithm: Algorithm::Rs256,
+                key: "-----BEGIN PUBLIC KEY-----\nMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEA\n-----END PUBLIC KEY-----",
+                ..Token::default()
+            },
+            Token {
+                name: "gateway_scope",
+                level: TokenLevel::Scope("account"),
+                algorithm: Algorithm::EdDSA,
+                key: "MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=",
+                ..Token::default()
+            }
+        ]);
+        let stmts: String = Query::new(tokens).build().unwrap();
+        assert!(stmts.contains("DEFINE TOKEN IF NOT EXISTS gateway ON DATABASE TYPE HS512 VALUE 'sNSY"));
+        assert!(stmts.contains("DEFINE TOKEN gateway_rsa ON NAMESPACE TYPE RS256 VALUE '-----BEGIN PUBLIC KEY-----"));
+        assert!(stmts.contains("DEFINE TOKEN gateway_scope ON SCOPE account TYPE EDDSA VALUE"));
+        let query: surrealdb::sql::Query = surrealdb::sql::parse(&stmts).unwrap();
+        let parsed: Vec<String> = query.0.0.iter().map(|stmt| stmt.to_string()).collect();
+        assert_eq!(format!("{};",parsed.join(";")),stmts);
+        let jwks = Token::build(Token {
+            name: "gateway_jwks",
+            jwks_url: "https://example.com/.well-known/jwks.json",
+            ..Token::default()
+        });
+        #[cfg(feature = "jwks")]
+        {
+            let stmt: String = jwks.unwrap();
+            assert_eq!(stmt,"DEFINE TOKEN gateway_jwks ON DATABASE TYPE JWKS VALUE 'https://example.com/.well-known/jwks.json'");
+            assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        }
+        #[cfg(not(feature = "jwks"))]
+        assert!(jwks.is_err());
+        // Expecting Error
+        assert!(Token::build(Token { name: "nokey", ..Token::default() }).is_err());
+        assert!(Token::build(Token { name: "both", key: "a", jwks_url: "b", ..Token::default() }).is_err());
+        assert!(Token::build(Token { name: "noscope", key: "a", level: TokenLevel::Scope(""), ..Token::default() }).is_err());
+    }
+
+}
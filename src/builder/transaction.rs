@@ -0,0 +1,108 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr;
+use crate::error::Error;
+use surrealdb::sql::statements::IfelseStatement;
+use surrealdb::sql::Statement;
+
+/// A guard of a transaction, the transaction is cancelled by a `THROW` when the condition is true.
+#[derive(Debug, Clone, Default)]
+pub struct Guard<'a>{
+    pub cond: Value,
+    pub message: &'a str,
+}
+
+/// # Transaction
+///
+/// * `stmts` - The built statements to run atomically, a statement can hold several statements such as the output of `Query::build`.
+/// * `guards` - The guards checked after the statements and before the `COMMIT`. See: `expr::value`.
+/// * `cancel` - End the transaction with `CANCEL TRANSACTION` instead of `COMMIT TRANSACTION`, nothing is applied.
+#[derive(Debug, Clone, Default)]
+pub struct Transaction<'a>{
+    pub stmts: Vec<String>,
+    pub guards: Vec<Guard<'a>>,
+    pub cancel: bool,
+}
+impl <'a> Transaction<'a> {
+    /// # Build a BEGIN TRANSACTION ... COMMIT TRANSACTION Block
+    /// ## Parameter
+    /// * `item` - The `Transaction` struct.
+    pub fn build(
+        item: Transaction
+    ) -> Result<String,Error> {
+        // Transaction =================================================
+        if item.stmts.is_empty() {
+            return Err(Error::invalid("Transaction","Transaction requires at least one statement."));
+        }
+        let mut stmts: Vec<String> = Vec::from(["BEGIN TRANSACTION".to_string()]);
+        for stmt in item.stmts {
+            let query: surrealdb::sql::Query = surrealdb::sql::parse(&stmt).map_err(|error| Error::invalid("Transaction",format!("Invalid statement {}: {}",stmt,error)))?;
+            if query.0.0.iter().any(|stmt| matches!(stmt, Statement::Begin(_) | Statement::Commit(_) | Statement::Cancel(_))) {
+                return Err(Error::invalid("Transaction","Transaction statements cannot begin, commit or cancel a transaction."));
+            }
+            stmts.push(stmt.trim().trim_end_matches(';').to_string());
+        }
+        for guard in item.guards {
+            if guard.cond.is_none() {
+                return Err(Error::invalid("Transaction","Transaction guard condition is required."));
+            }
+            if guard.message.is_empty() {
+                return Err(Error::invalid("Transaction","Transaction guard message is required."));
+            }
+            let mut stmt: IfelseStatement = IfelseStatement::default();
+            stmt.exprs.push((guard.cond, expr::value(&format!("{{ THROW {} }}",Value::from(guard.message)))?));
+            stmts.push(stmt.to_string());
+        }
+        stmts.push(match item.cancel {
+            true => "CANCEL TRANSACTION".to_string(),
+            false => "COMMIT TRANSACTION".to_string(),
+        });
+        Ok(format!("{};",stmts.join(";")))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::table::Table;
+    use crate::builder::field::Field;
+    use crate::builder::prelude::*;
+
+    #[test]
+    fn test_builder(){
+        let tables: String = Query::new(vec![
+            Table { name: "person", ..Table::default() },
+        ]).build().unwrap();
+        let fields: String = Query::new(vec![
+            Field { name: "email", table: "person", kind: Kind::String, ..Field::default() },
+        ]).build().unwrap();
+        let stmts: String = Transaction::build(Transaction {
+            stmts: vec![tables, fields, "UPDATE person SET email = string::lowercase(email)".to_string()],
+            guards: vec![Guard {
+                cond: expr::value("count(SELECT id FROM person WHERE email = NONE) > 0").unwrap(),
+                message: "Person without email.",
+            }],
+            ..Transaction::default()
+        }).unwrap();
+        assert!(stmts.starts_with("BEGIN TRANSACTION;DEFINE TABLE person "));
+        assert!(stmts.contains(";DEFINE FIELD email ON person TYPE string PERMISSIONS FULL;UPDATE person SET email = string::lowercase(email);"));
+        assert!(stmts.ends_with(";IF count((SELECT id FROM person WHERE email = NONE)) > 0 { THROW 'Person without email.'; };COMMIT TRANSACTION;"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        let stmts: String = Transaction::build(Transaction {
+            stmts: vec!["DELETE person".to_string()],
+            cancel: true,
+            ..Transaction::default()
+        }).unwrap();
+        assert_eq!(stmts,"BEGIN TRANSACTION;DELETE person;CANCEL TRANSACTION;");
+        // Expecting Error
+        assert!(Transaction::build(Transaction::default()).is_err());
+        assert!(Transaction::build(Transaction { stmts: vec!["BEGIN; DELETE person; COMMIT;".to_string()], ..Transaction::default() }).is_err());
+        assert!(Transaction::build(Transaction {
+            stmts: vec!["DELETE person".to_string()],
+            guards: vec![Guard { cond: Value::from(true), ..Guard::default() }],
+            ..Transaction::default()
+        }).is_err());
+    }
+
+}
@@ -0,0 +1,241 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr::{ self, Assignment, Return };
+use crate::error::Error;
+use surrealdb::sql::statements::UpdateStatement;
+use surrealdb::sql::Data;
+use std::collections::BTreeMap;
+
+/// A JSON Patch operation. See: <https://jsonpatch.com>
+#[derive(Debug, Clone)]
+pub enum PatchOp<'a> {
+    /// Add the value at the path.
+    Add(&'a str, Value),
+    /// Remove the value at the path.
+    Remove(&'a str),
+    /// Replace the value at the path.
+    Replace(&'a str, Value),
+    /// Copy the value from the first path to the second path.
+    Copy(&'a str, &'a str),
+    /// Move the value from the first path to the second path.
+    Move(&'a str, &'a str),
+    /// Fail the patch unless the value at the path equals the value.
+    Test(&'a str, Value),
+}
+
+impl PatchOp<'_> {
+    fn to_value(&self) -> Value {
+        let mut object: BTreeMap<&str, Value> = BTreeMap::new();
+        let (op, path) = match self {
+            PatchOp::Add(path, value) => { object.insert("value", value.clone()); ("add", path) },
+            PatchOp::Remove(path) => ("remove", path),
+            PatchOp::Replace(path, value) => { object.insert("value", value.clone()); ("replace", path) },
+            PatchOp::Copy(from, path) => { object.insert("from", Value::from(*from)); ("copy", path) },
+            PatchOp::Move(from, path) => { object.insert("from", Value::from(*from)); ("move", path) },
+            PatchOp::Test(path, value) => { object.insert("value", value.clone()); ("test", path) },
+        };
+        object.insert("op", Value::from(op));
+        object.insert("path", Value::from(*path));
+        Value::from(object)
+    }
+}
+
+/// The data clause of an UPDATE statement.
+#[derive(Debug, Clone)]
+pub enum UpdateData<'a> {
+    /// `SET field = value, field += value, field -= value`
+    Set(Vec<Assignment<'a>>),
+    /// `CONTENT { .. }` replaces the record, keeping the id.
+    Content(Value),
+    /// `MERGE { .. }` merges the object into the record.
+    Merge(Value),
+    /// `PATCH [ .. ]` applies JSON Patch operations to the record.
+    Patch(Vec<PatchOp<'a>>),
+    /// `REPLACE { .. }` replaces the record, keeping the id.
+    Replace(Value),
+}
+
+impl <'a> UpdateData<'a> {
+    pub(crate) fn build(self) -> Result<Data,Error> {
+        Ok(match self {
+            UpdateData::Set(assignments) => {
+                if assignments.is_empty() {
+                    return Err(Error::invalid("Update","SET requires at least one assignment."));
+                }
+                Data::SetExpression(expr::assignments(assignments)?)
+            },
+            UpdateData::Content(value) => Data::ContentExpression(value),
+            UpdateData::Merge(value) => Data::MergeExpression(value),
+            UpdateData::Patch(operations) => {
+                if operations.is_empty() {
+                    return Err(Error::invalid("Update","PATCH requires at least one operation."));
+                }
+                Data::PatchExpression(Value::from(
+                    operations.iter().map(PatchOp::to_value).collect::<Vec<Value>>()
+                ))
+            },
+            UpdateData::Replace(value) => Data::ReplaceExpression(value),
+        })
+    }
+}
+
+/// # Update
+///
+/// * `what` - The tables, record ids or record ranges to update. See: `expr::table`, `expr::thing`, `expr::range`.
+/// * `only` - Return a single record instead of an array.
+/// * `data` - The data clause which is value of enum `UpdateData`.
+/// * `cond` - The `WHERE` condition. See: `expr::value`.
+/// * `output` - The `RETURN` clause.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Update the records in parallel.
+#[derive(Debug, Clone, Default)]
+pub struct Update<'a>{
+    pub what: Vec<Value>,
+    pub only: bool,
+    pub data: Option<UpdateData<'a>>,
+    pub cond: Option<Value>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+}
+impl <'a> Update<'a> {
+    /// # Build an UPDATE Statement
+    /// ## Parameter
+    /// * `item` - The `Update` struct.
+    pub fn build(
+        item: Update
+    ) -> Result<String,Error> {
+        // Update ======================================================
+        Ok(Update::statement(item)?.to_string())
+    }
+
+    pub(crate) fn statement(item: Update) -> Result<UpdateStatement,Error> {
+        if item.what.is_empty() {
+            return Err(Error::invalid("Update","Update requires at least one table, record id or range."));
+        }
+        let mut stmt: UpdateStatement = UpdateStatement::default();
+        stmt.what       = expr::values(item.what);
+        stmt.only       = item.only;
+        stmt.parallel   = item.parallel;
+        if let Some(data) = item.data {
+            stmt.data = Some(data.build()?);
+        }
+        if let Some(value) = item.cond {
+            stmt.cond = Some(expr::cond(value));
+        }
+        if let Some(output) = item.output {
+            stmt.output = Some(expr::output(output)?);
+        }
+        if let Some(duration) = item.timeout {
+            stmt.timeout = Some(expr::timeout(duration));
+        }
+        Ok(stmt)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::db::Db;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_builder(){
+        let updates: Vec<Update> = Vec::from([
+            Update {
+                what: vec![expr::table("person")],
+                data: Some(UpdateData::Set(vec![
+                    Assignment::Set("active",Value::from(true)),
+                    Assignment::Add("visits",Value::from(1)),
+                    Assignment::Sub("tags",Value::from("guest")),
+                ])),
+                cond: Some(expr::value("age >= 18").unwrap()),
+                output: Some(Return::None),
+                timeout: Some(core::time::Duration::from_secs(10)),
+                parallel: true,
+                ..Update::default()
+            },
+            Update {
+                what: vec![expr::thing("person:tobie").unwrap()],
+                only: true,
+                data: Some(UpdateData::Merge(Value::from(HashMap::from([
+                    ("settings",Value::from(HashMap::from([("marketing",Value::from(false))]))),
+                ])))),
+                output: Some(Return::After),
+                ..Update::default()
+            },
+            Update {
+                what: vec![expr::range("person",Some(Value::from(1)),None).unwrap()],
+                data: Some(UpdateData::Content(Value::from(HashMap::from([("name",Value::from("anonymous"))])))),
+                output: Some(Return::Before),
+                ..Update::default()
+            },
+            Update {
+                what: vec![expr::thing("person:jaime").unwrap()],
+                data: Some(UpdateData::Patch(vec![
+                    PatchOp::Add("/tags/-",Value::from("admin")),
+                    PatchOp::Remove("/nickname"),
+                    PatchOp::Move("/name","/full_name"),
+                ])),
+                output: Some(Return::Diff),
+                ..Update::default()
+            },
+            Update {
+                what: vec![expr::thing("person:jaime").unwrap()],
+                data: Some(UpdateData::Replace(Value::from(HashMap::from([("name",Value::from("Jaime"))])))),
+                ..Update::default()
+            }
+        ]);
+        let stmts: String = Query::new(updates).build().unwrap();
+        assert!(stmts.contains("UPDATE person SET active = true, visits += 1, tags -= 'guest' WHERE age >= 18 RETURN NONE TIMEOUT 10s PARALLEL;"));
+        assert!(stmts.contains("UPDATE ONLY person:tobie MERGE { settings: { marketing: false } } RETURN AFTER;"));
+        assert!(stmts.contains("UPDATE person:1.. CONTENT { name: 'anonymous' } RETURN BEFORE;"));
+        assert!(stmts.contains("UPDATE person:jaime PATCH [{ op: 'add', path: '/tags/-', value: 'admin' }, { op: 'remove', path: '/nickname' }, { from: '/name', op: 'move', path: '/full_name' }] RETURN DIFF;"));
+        assert!(stmts.contains("UPDATE person:jaime REPLACE { name: 'Jaime' };"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        // Expecting Error
+        assert!(Update::build(Update::default()).is_err());
+        assert!(Update::build(Update {
+            what: vec![expr::table("person")],
+            data: Some(UpdateData::Patch(vec![])),
+            ..Update::default()
+        }).is_err());
+        assert!(expr::range("person",Some(Value::from(true)),None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        db.query("CREATE person:tobie SET age = 33, visits = 0, tags = ['guest']; CREATE person:jaime SET age = 12, visits = 0, nickname = 'jm', name = 'Jaime'").await.unwrap();
+        let updates: Vec<Update> = Vec::from([
+            Update {
+                what: vec![expr::table("person")],
+                data: Some(UpdateData::Set(vec![
+                    Assignment::Set("active",Value::from(true)),
+                    Assignment::Add("visits",Value::from(1)),
+                    Assignment::Sub("tags",Value::from("guest")),
+                ])),
+                cond: Some(expr::value("age >= 18").unwrap()),
+                output: Some(Return::None),
+                ..Update::default()
+            },
+            Update {
+                what: vec![expr::thing("person:jaime").unwrap()],
+                data: Some(UpdateData::Patch(vec![
+                    PatchOp::Remove("/nickname"),
+                    PatchOp::Move("/name","/full_name"),
+                ])),
+                output: Some(Return::Diff),
+                ..Update::default()
+            }
+        ]);
+        let report = db.execute(&Query::new(updates)).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let active: Vec<String> = db.query("SELECT VALUE meta::id(id) FROM person WHERE active = true AND visits = 1 AND tags = []").await.unwrap().take(0).unwrap();
+        assert_eq!(active,vec!["tobie"]);
+        let jaime: Option<String> = db.query("RETURN person:jaime.full_name").await.unwrap().take(0).unwrap();
+        assert_eq!(jaime.as_deref(),Some("Jaime"));
+    }
+
+}
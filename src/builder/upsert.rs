@@ -0,0 +1,166 @@
+use crate::builder::prelude::Value;
+use crate::builder::expr::Return;
+use crate::builder::update::{ Update, UpdateData };
+use crate::error::Error;
+use surrealdb::sql::statements::UpdateStatement;
+
+/// The major version of the SurrealDB server which runs the statement.
+/// The statements are checked with the parser of the SurrealDB 1.x client, `V2` statements can be built but not passed to `Transaction` or `Db::execute`.
+#[derive(Debug, Clone, Default)]
+pub enum SurrealVersion {
+    #[default]
+    V1,
+    V2
+}
+
+/// # Upsert
+///
+/// * `what` - The tables or record ids to upsert. See: `expr::table`, `expr::thing`.
+/// * `only` - Return a single record instead of an array.
+/// * `data` - The data clause which is value of enum `UpdateData`.
+/// * `cond` - The `WHERE` condition. See: `expr::value`.
+/// * `output` - The `RETURN` clause.
+/// * `timeout` - The maximum duration of the statement.
+/// * `parallel` - Upsert the records in parallel.
+/// * `version` - The version of the server. SurrealDB 1.x has no `UPSERT`, an `UPDATE` of a record id creates the record if it does not exist, so `V1` takes only record ids and no `cond`. Default: `SurrealVersion::V1`.
+#[derive(Debug, Clone, Default)]
+pub struct Upsert<'a>{
+    pub what: Vec<Value>,
+    pub only: bool,
+    pub data: Option<UpdateData<'a>>,
+    pub cond: Option<Value>,
+    pub output: Option<Return<'a>>,
+    pub timeout: Option<core::time::Duration>,
+    pub parallel: bool,
+    pub version: SurrealVersion,
+}
+impl <'a> Upsert<'a> {
+    /// # Build an UPSERT Statement
+    /// ## Parameter
+    /// * `item` - The `Upsert` struct.
+    pub fn build(
+        item: Upsert
+    ) -> Result<String,Error> {
+        // Upsert ======================================================
+        if item.data.is_none() {
+            return Err(Error::invalid("Upsert","Upsert requires a data clause."));
+        }
+        if matches!(item.version, SurrealVersion::V1) {
+            // On 1.x only an UPDATE of record ids creates missing records.
+            if let Some(what) = item.what.iter().find(|what| !matches!(what, Value::Thing(_))) {
+                return Err(Error::invalid("Upsert",format!("Upsert of {} requires SurrealDB 2.x, use a record id keyed by the natural key on SurrealDB 1.x.",what)));
+            }
+            // An UPDATE of a record id with a WHERE clause does not create the missing record.
+            if let Some(cond) = &item.cond {
+                return Err(Error::invalid("Upsert",format!("Upsert WHERE {} requires SurrealDB 2.x, an UPDATE with a condition does not create the record on SurrealDB 1.x.",cond)));
+            }
+        }
+        let stmt: UpdateStatement = Update::statement(Update {
+            what: item.what,
+            only: item.only,
+            data: item.data,
+            cond: item.cond,
+            output: item.output,
+            timeout: item.timeout,
+            parallel: item.parallel,
+        })?;
+        if matches!(item.version, SurrealVersion::V1) {
+            return Ok(stmt.to_string());
+        }
+        // The 1.x client has no UPSERT statement, the clauses are those of UPDATE.
+        let mut clauses: Vec<String> = Vec::from(["UPSERT".to_string()]);
+        if stmt.only {
+            clauses.push("ONLY".to_string());
+        }
+        clauses.push(stmt.what.to_string());
+        clauses.extend(stmt.data.map(|data| data.to_string()));
+        clauses.extend(stmt.cond.map(|cond| cond.to_string()));
+        clauses.extend(stmt.output.map(|output| output.to_string()));
+        clauses.extend(stmt.timeout.map(|timeout| timeout.to_string()));
+        if stmt.parallel {
+            clauses.push("PARALLEL".to_string());
+        }
+        Ok(clauses.join(" "))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::expr::{ self, Assignment };
+    use crate::db::Db;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_builder(){
+        let upserts: Vec<Upsert> = Vec::from([
+            Upsert {
+                what: vec![expr::table("customer")],
+                data: Some(UpdateData::Set(vec![
+                    Assignment::Set("email",Value::from("tobie@surrealdb.com")),
+                    Assignment::Add("syncs",Value::from(1)),
+                ])),
+                cond: Some(expr::value("email = 'tobie@surrealdb.com'").unwrap()),
+                output: Some(Return::After),
+                version: SurrealVersion::V2,
+                ..Upsert::default()
+            },
+            Upsert {
+                what: vec![expr::thing("customer:tobie").unwrap()],
+                only: true,
+                data: Some(UpdateData::Replace(Value::from(HashMap::from([("name",Value::from("Tobie"))])))),
+                version: SurrealVersion::V2,
+                ..Upsert::default()
+            }
+        ]);
+        let stmts: String = Query::new(upserts).build().unwrap();
+        assert_eq!(stmts,"UPSERT customer SET email = 'tobie@surrealdb.com', syncs += 1 WHERE email = 'tobie@surrealdb.com' RETURN AFTER;UPSERT ONLY customer:tobie REPLACE { name: 'Tobie' };");
+        let stmt: String = Upsert::build(Upsert {
+            what: vec![expr::thing("customer:tobie").unwrap()],
+            data: Some(UpdateData::Merge(Value::from(HashMap::from([("name",Value::from("Tobie"))])))),
+            output: Some(Return::None),
+            ..Upsert::default()
+        }).unwrap();
+        assert_eq!(stmt,"UPDATE customer:tobie MERGE { name: 'Tobie' } RETURN NONE");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        // Expecting Error
+        assert!(Upsert::build(Upsert { what: vec![expr::table("customer")], ..Upsert::default() }).is_err());
+        assert!(Upsert::build(Upsert {
+            what: vec![expr::table("customer")],
+            data: Some(UpdateData::Content(Value::from(HashMap::from([("name",Value::from("Tobie"))])))),
+            ..Upsert::default()
+        }).is_err());
+        assert!(matches!(Upsert::build(Upsert {
+            what: vec![expr::thing("customer:tobie").unwrap()],
+            data: Some(UpdateData::Set(vec![Assignment::Set("name",Value::from("Tobie"))])),
+            cond: Some(expr::value("active = true").unwrap()),
+            ..Upsert::default()
+        }), Err(Error::Invalid { kind: "Upsert", .. })));
+    }
+
+    #[tokio::test]
+    async fn test_execute(){
+        let db = Db::memory("test","test").await.unwrap();
+        let upsert = || Upsert {
+            what: vec![expr::thing("customer:tobie").unwrap()],
+            data: Some(UpdateData::Set(vec![Assignment::Add("syncs",Value::from(1))])),
+            ..Upsert::default()
+        };
+        let customers: Option<i64> = db.query("RETURN count(SELECT id FROM customer)").await.unwrap().take(0).unwrap();
+        assert_eq!(customers,Some(0));
+        // The record is created by the first upsert and updated by the second.
+        let report = db.execute(&Query::new(vec![upsert()])).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let syncs: Option<i64> = db.query("RETURN customer:tobie.syncs").await.unwrap().take(0).unwrap();
+        assert_eq!(syncs,Some(1));
+        let report = db.execute(&Query::new(vec![upsert()])).await.unwrap();
+        assert!(report.is_ok(),"{:?}",report.outcomes);
+        let syncs: Option<i64> = db.query("RETURN customer:tobie.syncs").await.unwrap().take(0).unwrap();
+        assert_eq!(syncs,Some(2));
+        let customers: Option<i64> = db.query("RETURN count(SELECT id FROM customer)").await.unwrap().take(0).unwrap();
+        assert_eq!(customers,Some(1));
+    }
+
+}
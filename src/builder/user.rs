@@ -0,0 +1,188 @@
+use crate::builder::prelude::{ Ident, Strand };
+use crate::error::Error;
+use surrealdb::sql::statements::DefineUserStatement;
+use surrealdb::sql::Base;
+use std::fmt;
+
+/// The level on which the user is defined.
+#[derive(Debug, Clone, Default)]
+pub enum UserLevel {
+    #[default]
+    Root,
+    Namespace,
+    Database
+}
+
+/// The built-in roles of a system user.
+#[derive(Debug, Clone)]
+pub enum UserRole {
+    Owner,
+    Editor,
+    Viewer
+}
+
+impl fmt::Display for UserRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UserRole::Owner => "owner",
+            UserRole::Editor => "editor",
+            UserRole::Viewer => "viewer",
+        })
+    }
+}
+
+/// # User
+///
+/// * `name` - The user name.
+/// * `level` - The level of the user which is value of enum `UserLevel`. Default: `UserLevel::Root`.
+/// * `password` - The plaintext password. It is hashed with Argon2 and rendered as `PASSHASH` unless `expose_password` is `true`.
+/// * `passhash` - An already hashed password. Only one of `password` or `passhash` can be set.
+/// * `roles` - The roles of the user. Default: `UserRole::Viewer`.
+/// * `comment` - Comment on the user.
+/// * `if_not_exists` - Create or define the user if not exists.
+/// * `expose_password` - Render the plaintext `password` as `PASSWORD` in the statement. The statement must not be logged.
+#[derive(Clone, Default)]
+pub struct User<'a>{
+    pub name: &'a str,
+    pub level: UserLevel,
+    pub password: &'a str,
+    pub passhash: &'a str,
+    pub roles: Vec<UserRole>,
+    pub comment: &'a str,
+    pub if_not_exists: bool,
+    pub expose_password: bool,
+}
+
+impl <'a> fmt::Debug for User<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("name", &self.name)
+            .field("level", &self.level)
+            .field("password", &if self.password.is_empty() { "" } else { "<redacted>" })
+            .field("passhash", &self.passhash)
+            .field("roles", &self.roles)
+            .field("comment", &self.comment)
+            .field("if_not_exists", &self.if_not_exists)
+            .field("expose_password", &self.expose_password)
+            .finish()
+    }
+}
+
+impl <'a> User<'a> {
+    /// # Build a DEFINE USER Statement
+    /// ## Parameter
+    /// * `item` - The `User` struct.
+    pub fn build(
+        item: User
+    ) -> Result<String,Error> {
+        // User ========================================================
+        if item.name.is_empty() {
+            return Err(Error::MissingName { kind: "User", field: "name" });
+        }
+        if item.password.is_empty() == item.passhash.is_empty() {
+            return Err(Error::invalid("User","User requires either a password or a passhash."));
+        }
+        let base: Base = match item.level {
+            UserLevel::Root => Base::Root,
+            UserLevel::Namespace => Base::Ns,
+            UserLevel::Database => Base::Db,
+        };
+        let roles: Vec<Ident> = match item.roles.is_empty() {
+            true => vec![Ident::from(UserRole::Viewer.to_string())],
+            false => item.roles.iter().map(|role| Ident::from(role.to_string())).collect()
+        };
+        if item.expose_password && !item.password.is_empty() {
+            // DefineUserStatement only renders PASSHASH.
+            let roles: Vec<String> = roles.iter().map(|role| role.to_string().to_uppercase()).collect();
+            let mut stmt: String = format!(
+                "DEFINE USER{} {} ON {} PASSWORD {} ROLES {}",
+                if item.if_not_exists { " IF NOT EXISTS" } else { "" },
+                Ident::from(item.name),
+                base,
+                Strand::from(item.password),
+                roles.join(", ")
+            );
+            if !item.comment.is_empty() {
+                stmt.push_str(&format!(" COMMENT {}",Strand::from(item.comment)));
+            }
+            return Ok(stmt);
+        }
+        let mut stmt: DefineUserStatement = match item.password.is_empty() {
+            true => {
+                let mut stmt: DefineUserStatement = DefineUserStatement::default();
+                stmt.name = Ident::from(item.name.to_string());
+                stmt.base = base;
+                stmt.hash = item.passhash.to_string();
+                stmt
+            }
+            false => DefineUserStatement::from((base, item.name, item.password))
+        };
+        stmt.if_not_exists = item.if_not_exists;
+        stmt.roles = roles;
+        if !item.comment.is_empty() {
+            stmt.comment = Some(Strand::from(item.comment));
+        }
+        Ok(stmt.to_string())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+
+    #[test]
+    fn test_builder(){
+        let users: Vec<User> = Vec::from([
+            User {
+                name: "service",
+                level: UserLevel::Database,
+                password: "s3cr3t",
+                roles: vec![UserRole::Editor,UserRole::Viewer],
+                comment: "service account",
+                if_not_exists: true,
+                ..User::default()
+            },
+            User {
+                name: "admin",
+                level: UserLevel::Namespace,
+                passhash: "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2g",
+                roles: vec![UserRole::Owner],
+                ..User::default()
+            }
+        ]);
+        let stmts: String = Query::new(users).build().unwrap();
+        assert!(!stmts.contains("s3cr3t"));
+        assert!(stmts.contains("DEFINE USER IF NOT EXISTS service ON DATABASE PASSHASH '$argon2id$"));
+        assert!(stmts.contains("ROLES EDITOR, VIEWER COMMENT 'service account'"));
+        assert!(stmts.contains("DEFINE USER admin ON NAMESPACE PASSHASH '$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2g' ROLES OWNER"));
+        assert!(surrealdb::sql::parse(&stmts).is_ok(),"Invalid statement: {}",stmts);
+        let user: User = User {
+            name: "root_user",
+            password: "s3cr3t",
+            roles: vec![UserRole::Owner],
+            expose_password: true,
+            ..User::default()
+        };
+        assert!(!format!("{:?}",user).contains("s3cr3t"));
+        let stmt: String = User::build(user).unwrap();
+        assert_eq!(stmt,"DEFINE USER root_user ON ROOT PASSWORD 's3cr3t' ROLES OWNER");
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        let stmt: String = User::build(User {
+            name: "quoted",
+            level: UserLevel::Database,
+            password: "it's \"s3cr3t\"",
+            comment: "exposed",
+            if_not_exists: true,
+            expose_password: true,
+            ..User::default()
+        }).unwrap();
+        assert_eq!(stmt,format!("DEFINE USER IF NOT EXISTS quoted ON DATABASE PASSWORD {} ROLES VIEWER COMMENT 'exposed'",Strand::from("it's \"s3cr3t\"")));
+        assert!(surrealdb::sql::parse(&stmt).is_ok(),"Invalid statement: {}",stmt);
+        // Expecting Error
+        assert!(User::build(User { name: "nopass", ..User::default() }).is_err());
+        assert!(User::build(User { name: "both", password: "a", passhash: "b", ..User::default() }).is_err());
+    }
+
+}
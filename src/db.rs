@@ -1,18 +1,124 @@
+use surrealdb::engine::any::{ self, Any };
+use surrealdb::opt::auth::{ Root, Namespace, Database, Scope, Jwt };
+use surrealdb::opt::Config;
+use surrealdb::{ Response, Surreal };
+use crate::error::Error;
+use crate::builder::query::Query;
+use crate::builder::statement::Statement;
+use crate::builder::introspect::Introspection;
+use crate::builder::analyzer::Analyzer;
+use surrealdb::sql::{ Ident, Statement as SqlStatement };
+use std::collections::BTreeMap;
+use core::time::Duration;
+use std::future::Future;
+use std::fmt;
 
-use surrealdb::engine::remote::ws::Ws;
-use surrealdb::opt::auth::{ Root, Jwt };
-use surrealdb::Surreal;
-use surrealdb::engine::remote::ws::Client;
+type Info = BTreeMap<String, BTreeMap<String, String>>;
+
+/// The credentials to signin with.
+#[derive(Clone, Default)]
+pub enum Credentials<'a> {
+    /// No signin, the session is anonymous.
+    #[default]
+    None,
+    /// A root user.
+    Root { username: &'a str, password: &'a str },
+    /// A user of the namespace of the `DbConfig`.
+    Namespace { username: &'a str, password: &'a str },
+    /// A user of the database of the `DbConfig`.
+    Database { username: &'a str, password: &'a str },
+    /// A record user of a scope of the database of the `DbConfig`, `params` are passed to the `SIGNIN` of the scope.
+    Scope { scope: &'a str, params: serde_json::Value },
+    /// A JWT issued by the server or by a `DEFINE TOKEN`.
+    Token(&'a str),
+}
+
+impl fmt::Debug for Credentials<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Credentials::None => write!(f, "None"),
+            Credentials::Root { username, .. } => f.debug_struct("Root").field("username", username).finish_non_exhaustive(),
+            Credentials::Namespace { username, .. } => f.debug_struct("Namespace").field("username", username).finish_non_exhaustive(),
+            Credentials::Database { username, .. } => f.debug_struct("Database").field("username", username).finish_non_exhaustive(),
+            Credentials::Scope { scope, .. } => f.debug_struct("Scope").field("scope", scope).finish_non_exhaustive(),
+            Credentials::Token(_) => write!(f, "Token(..)"),
+        }
+    }
+}
+
+/// # DbConfig
+///
+/// * `endpoint` - The address of the server, e.g. `wss://db.example.com`. The scheme is one of `ws`, `wss`, `http` or `https`, `ws` is used when the scheme is omitted. The `kv-mem` feature adds `mem://`, see: `Db::memory`.
+/// * `credentials` - The credentials which is value of enum `Credentials`. Default: `Credentials::None`.
+/// * `namespace` - The namespace to use, required by namespace, database and scope credentials.
+/// * `database` - The database to use, required by database and scope credentials.
+/// * `connect_timeout` - The maximum duration to connect, signin and select the namespace and database.
+/// * `query_timeout` - The maximum duration of a query run by `Db::query`.
+#[derive(Debug, Clone, Default)]
+pub struct DbConfig<'a>{
+    pub endpoint: &'a str,
+    pub credentials: Credentials<'a>,
+    pub namespace: &'a str,
+    pub database: &'a str,
+    pub connect_timeout: Option<Duration>,
+    pub query_timeout: Option<Duration>,
+}
+
+/// The outcome of a statement run by `Db::execute`.
+/// * `item` - The index of the builder item of the `Query` which produced the statement.
+/// * `statement` - The index of the statement in the response.
+/// * `error` - The `Error::Execution` of the statement, `None` when the statement succeeded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outcome{
+    pub item: usize,
+    pub statement: usize,
+    pub error: Option<Error>,
+}
+
+/// The report of `Db::execute`.
+/// * `outcomes` - The outcome of every statement of the response, in order.
+/// * `response` - The response without the errors, the results of the succeeded statements can still be taken.
+#[derive(Debug)]
+pub struct Report{
+    pub outcomes: Vec<Outcome>,
+    pub response: Response,
+}
+
+impl Report {
+    /// Whether every statement succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.outcomes.iter().all(|outcome| outcome.error.is_none())
+    }
+
+    /// The outcomes of the succeeded statements.
+    pub fn succeeded(&self) -> impl Iterator<Item = &Outcome> {
+        self.outcomes.iter().filter(|outcome| outcome.error.is_none())
+    }
+
+    /// The outcomes of the failed statements.
+    pub fn failed(&self) -> impl Iterator<Item = &Outcome> {
+        self.outcomes.iter().filter(|outcome| outcome.error.is_some())
+    }
+
+    /// The first failed statement as an `Error::Query` carrying the index of its builder item.
+    pub fn check(&self) -> Result<(),Error> {
+        match self.failed().next() {
+            Some(Outcome { item, error: Some(error), .. }) => Err(Error::Query { index: *item, error: Box::new(error.clone()) }),
+            _ => Ok(())
+        }
+    }
+}
 
 #[derive(Debug)]
 pub struct Db{
-    pub client: Surreal<Client>,
-    pub jwt: Jwt,
+    pub client: Surreal<Any>,
+    pub jwt: Option<Jwt>,
+    pub query_timeout: Option<Duration>,
 }
 
 impl Db {
     /// Establish a SurrealDB Client connection.
-    /// Note: This is just a simple way to connect to SurrealDB Instance.
+    /// Note: This is just a simple way to connect to SurrealDB Instance, it panics when the connection fails. See: `Db::connect`.
     /// ## Parameters
     /// * `address` - The host:port address.
     /// * `username` - The registered username of the user.
@@ -23,30 +129,204 @@ impl Db {
     /// * `client` - A SurrealDB client instance.
     /// * `jwt` - A jwt value.
     /// 
-    pub async fn new<'a>(address: &str, username: &str, password: &str, namespace: &str, database: &str) -> Self {
-        match Surreal::new::<Ws>(address).await {
-            Ok(client) => {
-                match client.signin(Root { username, password }).await {
-                    Ok(jwt) => {
-
-                        if let Err(error) = client.use_ns(namespace).use_db(database).await {
-                            panic!("Unable to connect namespace {} / database: {}. ERROR: {:#?}",namespace,database,error);
-                        }
-                        return Self {
-                            client,
-                            jwt
-                        }
-                    }
-                    Err(error) => {
+    pub async fn new(address: &str, username: &str, password: &str, namespace: &str, database: &str) -> Self {
+        match Db::connect(DbConfig {
+            endpoint: address,
+            credentials: Credentials::Root { username, password },
+            namespace,
+            database,
+            ..DbConfig::default()
+        }).await {
+            Ok(db) => db,
+            Err(error) => panic!("{}",error)
+        }
+    }
 
-                        panic!("Unable to signin to the database. ERROR:{:#?}",error);
-                    }
+    /// # Connect to a SurrealDB Instance
+    /// ## Parameter
+    /// * `config` - The `DbConfig` struct.
+    pub async fn connect(config: DbConfig<'_>) -> Result<Self,Error> {
+        let endpoint: String = Db::endpoint(config.endpoint)?;
+        match config.credentials {
+            Credentials::Namespace { .. } if config.namespace.is_empty() => {
+                return Err(Error::MissingName { kind: "DbConfig", field: "namespace" });
+            },
+            Credentials::Database { .. } | Credentials::Scope { .. } if config.namespace.is_empty() || config.database.is_empty() => {
+                return Err(Error::MissingName { kind: "DbConfig", field: match config.namespace.is_empty() { true => "namespace", false => "database" } });
+            },
+            _ => {}
+        }
+        let connect = async {
+            let client: Surreal<Any> = any::connect((endpoint, Config::new().query_timeout(config.query_timeout)))
+                .await
+                .map_err(|error| Error::Connection(error.to_string()))?;
+            let jwt: Option<Jwt> = match config.credentials {
+                Credentials::None => None,
+                Credentials::Root { username, password } => Some(
+                    client.signin(Root { username, password }).await
+                ),
+                Credentials::Namespace { username, password } => Some(
+                    client.signin(Namespace { namespace: config.namespace, username, password }).await
+                ),
+                Credentials::Database { username, password } => Some(
+                    client.signin(Database { namespace: config.namespace, database: config.database, username, password }).await
+                ),
+                Credentials::Scope { scope, params } => Some(
+                    client.signin(Scope { namespace: config.namespace, database: config.database, scope, params }).await
+                ),
+                Credentials::Token(token) => Some(
+                    client.authenticate(token).await.map(|_| Jwt::from(token.to_string()))
+                ),
+            }.transpose().map_err(|error| Error::Auth(error.to_string()))?;
+            if !config.namespace.is_empty() {
+                client.use_ns(config.namespace).await
+                    .map_err(|error| Error::Connection(format!("Unable to use namespace {}: {}",config.namespace,error)))?;
+            }
+            if !config.database.is_empty() {
+                client.use_db(config.database).await
+                    .map_err(|error| Error::Connection(format!("Unable to use database {}: {}",config.database,error)))?;
+            }
+            Ok(Self {
+                client,
+                jwt,
+                query_timeout: config.query_timeout,
+            })
+        };
+        Db::timeout(config.connect_timeout, connect, Error::Connection).await
+    }
+
+    /// # Connect to an in-memory database
+    /// The database runs in-process and is dropped with the `Db`, no server is required.
+    /// Requires the `kv-mem` feature.
+    /// ## Parameters
+    /// * `namespace` - The namespace to use.
+    /// * `database` - The database to use.
+    #[cfg(any(feature = "kv-mem", test))]
+    pub async fn memory(namespace: &str, database: &str) -> Result<Self,Error> {
+        Db::connect(DbConfig {
+            endpoint: "mem://",
+            namespace,
+            database,
+            ..DbConfig::default()
+        }).await
+    }
+
+    /// # Run statements
+    /// The query fails with `Error::Execution` when it exceeds the `query_timeout` of the `DbConfig`.
+    /// ## Parameter
+    /// * `stmts` - The statements, e.g. the output of `Query::build`.
+    pub async fn query(&self, stmts: impl Into<String>) -> Result<Response,Error> {
+        let query = async {
+            self.client.query(stmts.into()).await.map_err(|error| Error::Execution(error.to_string()))
+        };
+        Db::timeout(self.query_timeout, query, Error::Execution).await
+    }
+
+    /// # Run the items of a Query
+    /// Builds every item of the query, runs the batch and maps the error of every statement back to the item which produced it.
+    /// A search index must name an analyzer defined before it in the batch or in the database, SurrealDB only reports it on the next write.
+    /// Fails only when an item cannot be built or the batch cannot be sent, failed statements are reported in the `Report`.
+    /// ## Parameter
+    /// * `query` - The `Query` struct.
+    pub async fn execute<T: Statement>(&self, query: &Query<T>) -> Result<Report,Error> {
+        // The item of every statement in the response.
+        let mut items: Vec<usize> = Vec::new();
+        let mut stmts: Vec<String> = Vec::with_capacity(query.items.len());
+        let mut analyzers: Vec<String> = Vec::new();
+        let mut deployed: bool = false;
+        for (index, item) in query.items.iter().enumerate() {
+            let wrap = |error: Error| Error::Query { index, error: Box::new(error) };
+            let stmt: String = item.statement().map_err(wrap)?;
+            let parsed: surrealdb::sql::Query = surrealdb::sql::parse(&stmt)
+                .map_err(|error| wrap(Error::invalid("Query",format!("Invalid statement {}: {}",stmt,error))))?;
+            if let Err(error) = Analyzer::check(&parsed.0.0, &mut analyzers) {
+                if deployed {
+                    return Err(wrap(error));
                 }
+                let db: Info = Db::info(&mut self.query("INFO FOR DB;").await?, 0)?;
+                analyzers.extend(db.get("analyzers").map(|analyzers| analyzers.keys().cloned().collect::<Vec<String>>()).unwrap_or_default());
+                deployed = true;
+                Analyzer::check(&parsed.0.0, &mut analyzers).map_err(wrap)?;
             }
-            Err(error) => {
-                panic!("Unable to connect to the database. ERROR:{:#?}",error);
+            // BEGIN, COMMIT and CANCEL have no result in the response.
+            items.extend(parsed.0.0.iter()
+                .filter(|stmt| !matches!(stmt, SqlStatement::Begin(_) | SqlStatement::Commit(_) | SqlStatement::Cancel(_)))
+                .map(|_| index));
+            stmts.push(stmt.trim_end_matches(';').to_string());
+        }
+        let mut response: Response = self.query(format!("{};",stmts.join(";"))).await?;
+        let mut errors = response.take_errors();
+        let outcomes: Vec<Outcome> = items.into_iter().enumerate().map(|(statement, item)| Outcome {
+            item,
+            statement,
+            error: errors.remove(&statement).map(|error| Error::Execution(error.to_string())),
+        }).collect();
+        Ok(Report {
+            outcomes,
+            response,
+        })
+    }
+
+    /// # Read the Schema of the Database
+    /// Runs `INFO FOR DB` and `INFO FOR TABLE` for every table and parses the returned definitions. See: `Introspection::schema`.
+    pub async fn introspect(&self) -> Result<Introspection,Error> {
+        let mut introspection: Introspection = Introspection::default();
+        let db: Info = Db::info(&mut self.query("INFO FOR DB;").await?, 0)?;
+        for kind in ["params", "functions", "analyzers", "tables"] {
+            for stmt in db.get(kind).into_iter().flat_map(|stmts| stmts.values()) {
+                introspection.insert(stmt)?;
             }
         }
+        if !introspection.tables.is_empty() {
+            let stmts: Vec<String> = introspection.tables.keys()
+                .map(|table| format!("INFO FOR TABLE {};",Ident::from(table.as_str())))
+                .collect();
+            let mut response: Response = self.query(stmts.concat()).await?;
+            for index in 0..stmts.len() {
+                let table: Info = Db::info(&mut response, index)?;
+                for kind in ["fields", "indexes", "events"] {
+                    for stmt in table.get(kind).into_iter().flat_map(|stmts| stmts.values()) {
+                        introspection.insert(stmt)?;
+                    }
+                }
+            }
+        }
+        Ok(introspection)
+    }
+
+    /// The result of an `INFO FOR` statement, the definitions keyed by their kind and name.
+    fn info(response: &mut Response, index: usize) -> Result<Info,Error> {
+        response.take::<Option<Info>>(index)
+            .map(Option::unwrap_or_default)
+            .map_err(|error| Error::Execution(error.to_string()))
+    }
+
+    /// Prefix the endpoint with `ws://` when the scheme is omitted and check the scheme.
+    fn endpoint(endpoint: &str) -> Result<String,Error> {
+        if endpoint.is_empty() {
+            return Err(Error::MissingName { kind: "DbConfig", field: "endpoint" });
+        }
+        match endpoint.split_once("://") {
+            None => Ok(format!("ws://{}",endpoint)),
+            Some(("ws" | "wss" | "http" | "https", _)) => Ok(endpoint.to_string()),
+            #[cfg(any(feature = "kv-mem", test))]
+            Some(("mem", _)) => Ok(endpoint.to_string()),
+            Some((scheme, _)) => Err(Error::Connection(format!("Unsupported endpoint scheme {}.",scheme))),
+        }
+    }
+
+    async fn timeout<T>(
+        duration: Option<Duration>,
+        future: impl Future<Output = Result<T,Error>>,
+        error: fn(String) -> Error
+    ) -> Result<T,Error> {
+        match duration {
+            Some(duration) => match tokio::time::timeout(duration, future).await {
+                Ok(result) => result,
+                Err(_) => Err(error(format!("Timed out after {:?}.",duration))),
+            },
+            None => future.await,
+        }
     }
 }
 
@@ -69,7 +349,7 @@ mod tests {
     #[tokio::test]
     async fn test_connection() {
     
-        let db = Db::new("127.0.0.1:6080","root","root","test","test").await;
+        let db = Db::memory("test","test").await.unwrap();
         let created: Option<MyTestRecord> = db.client.create(("test","test")).content(MyTest {
             name: "test".to_string()
         }).await.unwrap();
@@ -78,4 +358,94 @@ mod tests {
         assert!(deleted.is_some());
     
     }
+
+    #[tokio::test]
+    async fn test_connect_errors() {
+        let error = Db::connect(DbConfig { endpoint: "tikv://127.0.0.1:2379", ..DbConfig::default() }).await.unwrap_err();
+        assert!(matches!(error, Error::Connection(_)),"{}",error);
+        let error = Db::connect(DbConfig {
+            endpoint: "127.0.0.1:6080",
+            credentials: Credentials::Database { username: "root", password: "root" },
+            namespace: "test",
+            ..DbConfig::default()
+        }).await.unwrap_err();
+        assert_eq!(error,Error::MissingName { kind: "DbConfig", field: "database" });
+        let error = Db::connect(DbConfig {
+            endpoint: "http://127.0.0.1:1",
+            credentials: Credentials::Root { username: "root", password: "root" },
+            connect_timeout: Some(Duration::from_secs(5)),
+            ..DbConfig::default()
+        }).await.unwrap_err();
+        assert!(matches!(error, Error::Connection(_) | Error::Auth(_)),"{}",error);
+        assert!(!format!("{:?}",Credentials::Root { username: "root", password: "secret" }).contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_execute() {
+        use crate::builder::prelude::*;
+        use crate::builder::table::Table;
+        use crate::builder::field::Field;
+        use crate::builder::index::Index;
+        use crate::builder::analyzer::Analyzer;
+        use surrealdb::sql::statements::DefineStatement;
+        use crate::builder::create::Create;
+        use crate::builder::transaction::{ Transaction, Guard };
+        use crate::builder::expr;
+
+        let db = Db::memory("test","test").await.unwrap();
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "test_execute", kind: TableKind::Normal, ..Table::default() }),
+            Box::new(Field { name: "email", table: "test_execute", kind: Kind::String, ..Field::default() }),
+            Box::new(Create { what: vec![expr::thing("test_execute:one").unwrap()], set: vec![Assignment::Set("email",Value::from(1))], ..Create::default() }),
+            Box::new(Transaction {
+                stmts: vec!["CREATE test_execute:two SET email = 'two@surrealdb.com'".to_string()],
+                guards: vec![Guard { cond: Value::from(true), message: "Rollback." }],
+                ..Transaction::default()
+            }),
+            Box::new(Create { what: vec![expr::thing("test_execute:three").unwrap()], set: vec![Assignment::Set("email",Value::from("three@surrealdb.com"))], ..Create::default() }),
+        ];
+        let report = db.execute(&Query::new(items)).await.unwrap();
+        assert!(!report.is_ok());
+        assert_eq!(report.outcomes.iter().map(|outcome| outcome.item).collect::<Vec<usize>>(),vec![0,1,2,3,3,4]);
+        assert_eq!(report.succeeded().map(|outcome| outcome.statement).collect::<Vec<usize>>(),vec![0,1,5]);
+        assert_eq!(report.failed().map(|outcome| outcome.item).collect::<Vec<usize>>(),vec![2,3,3]);
+        assert_eq!(report.check().unwrap_err().index(),Some(2));
+        // Expecting Error
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "test_execute", ..Table::default() }),
+            Box::new(Field { table: "test_execute", ..Field::default() }),
+        ];
+        assert_eq!(db.execute(&Query::new(items)).await.unwrap_err().index(),Some(1));
+        // A search index of an unknown analyzer, also inside a transaction.
+        let search = |name: &'static str, analyzer: &str| Index {
+            name,
+            table: "test_execute",
+            cols: vec![Idiom::from("email")],
+            index: match surrealdb::sql::parse(&format!("DEFINE INDEX {} ON test_execute FIELDS email SEARCH ANALYZER {} BM25",name,analyzer)).unwrap().0.0.remove(0) {
+                surrealdb::sql::Statement::Define(DefineStatement::Index(define)) => define.index,
+                _ => IndexType::Idx,
+            },
+            ..Index::default()
+        };
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "test_execute", kind: TableKind::Normal, ..Table::default() }),
+            Box::new(search("email_search", "english")),
+        ];
+        assert_eq!(db.execute(&Query::new(items)).await.unwrap_err(),Error::Query { index: 1, error: Box::new(Error::UnknownAnalyzer("english".to_string())) });
+        let items: Vec<Transaction> = vec![Transaction {
+            stmts: vec![Index::build(search("email_search", "english")).unwrap()],
+            ..Transaction::default()
+        }];
+        assert!(matches!(db.execute(&Query::new(items)).await, Err(Error::Query { index: 0, .. })));
+        // Defined before the index in the batch or in the database.
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Analyzer { name: "english", tokenizers: vec![Tokenizer::Blank], ..Analyzer::default() }),
+            Box::new(search("email_search", "english")),
+        ];
+        assert!(db.execute(&Query::new(items)).await.unwrap().is_ok());
+        let items: Vec<Index> = vec![search("email_search_2", "english")];
+        assert!(db.execute(&Query::new(items)).await.unwrap().is_ok());
+        let items: Vec<Index> = vec![search("email_search_3", "english"),search("email_search_4", "french")];
+        assert_eq!(db.execute(&Query::new(items)).await.unwrap_err(),Error::Query { index: 1, error: Box::new(Error::UnknownAnalyzer("french".to_string())) });
+    }
 }
\ No newline at end of file
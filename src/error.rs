@@ -0,0 +1,121 @@
+use std::fmt;
+
+/// The errors of the builders and of the database client.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// A required name is empty.
+    /// * `kind` - The builder, e.g. `Table`.
+    /// * `field` - The empty field, e.g. `name`.
+    MissingName { kind: &'static str, field: &'static str },
+    /// An identifier, field path, record id or expression cannot be parsed.
+    /// * `kind` - What was parsed, e.g. `record id`.
+    /// * `value` - The parsed text.
+    /// * `message` - The reason of the parser.
+    InvalidIdentifier { kind: &'static str, value: String, message: String },
+    /// A RELATE statement does not match the definition of the relation table.
+    InvalidRelation(String),
+    /// A statement uses a table which is not defined.
+    UnknownTable(String),
+    /// A search index uses an analyzer which is not defined.
+    UnknownAnalyzer(String),
+    /// The options of a builder are missing or conflict with each other.
+    /// * `kind` - The builder, e.g. `Insert`.
+    /// * `message` - The reason.
+    Invalid { kind: &'static str, message: String },
+    /// Unable to connect to the database.
+    Connection(String),
+    /// Unable to signin to the database.
+    Auth(String),
+    /// The database failed to run a statement.
+    Execution(String),
+    /// An item of a `Query` batch failed.
+    /// * `index` - The index of the failing item in the batch.
+    /// * `error` - The error of the item.
+    Query { index: usize, error: Box<Error> },
+}
+
+impl Error {
+    /// The index of the failing item when the error comes from a `Query` batch.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            Error::Query { index, .. } => Some(*index),
+            _ => None
+        }
+    }
+
+    pub(crate) fn invalid(kind: &'static str, message: impl Into<String>) -> Self {
+        Error::Invalid { kind, message: message.into() }
+    }
+
+    pub(crate) fn identifier(kind: &'static str, value: impl Into<String>, message: impl fmt::Display) -> Self {
+        Error::InvalidIdentifier { kind, value: value.into(), message: message.to_string() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingName { kind, field } => write!(f, "{} {} is required.", kind, field),
+            Error::InvalidIdentifier { kind, value, message } => write!(f, "Invalid {} {}: {}", kind, value, message),
+            Error::InvalidRelation(message) => write!(f, "{}", message),
+            Error::UnknownTable(table) => write!(f, "Table {} is not defined.", table),
+            Error::UnknownAnalyzer(analyzer) => write!(f, "Analyzer {} is not defined.", analyzer),
+            Error::Invalid { message, .. } => write!(f, "{}", message),
+            Error::Connection(message) => write!(f, "Unable to connect to the database. ERROR: {}", message),
+            Error::Auth(message) => write!(f, "Unable to signin to the database. ERROR: {}", message),
+            Error::Execution(message) => write!(f, "Unable to execute the statement. ERROR: {}", message),
+            Error::Query { index, error } => write!(f, "QUERY STATEMENT ERROR: item {}: {}", index, error),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Query { error, .. } => Some(error.as_ref()),
+            _ => None
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::query::Query;
+    use crate::builder::table::Table;
+    use crate::builder::field::Field;
+    use crate::builder::relate::Relate;
+    use crate::builder::transaction::Transaction;
+    use crate::builder::statement::Statement;
+    use crate::builder::prelude::Value;
+    use crate::builder::expr;
+
+    #[test]
+    fn test_error(){
+        let items: Vec<Box<dyn Statement>> = vec![
+            Box::new(Table { name: "person", ..Table::default() }),
+            Box::new(Field { name: "email", ..Field::default() }),
+        ];
+        let error: Error = Query::new(items).build().unwrap_err();
+        assert_eq!(error.index(),Some(1));
+        assert!(matches!(&error, Error::Query { error, .. } if **error == Error::MissingName { kind: "Field", field: "table" }));
+        assert!(std::error::Error::source(&error).is_some());
+        assert_eq!(error.to_string(),"QUERY STATEMENT ERROR: item 1: Field table is required.");
+        assert!(matches!(expr::thing("person"), Err(Error::InvalidIdentifier { kind: "record id", .. })));
+        assert_eq!(expr::range("person",None,Some(Value::from(true))).unwrap_err().to_string(),"Invalid record range true: Expecting a number, string, array or object record id.");
+        assert_eq!(expr::range("person",None,Some(Value::from(1000))).unwrap().to_string(),"person:..1000");
+        assert_eq!(expr::range("person",Some(Value::from("a")),None).unwrap().to_string(),"person:a..");
+        assert!(matches!(expr::kind("array<"), Err(Error::InvalidIdentifier { kind: "type", message, .. }) if !message.is_empty()));
+        assert!(matches!(expr::permissions("FOR"), Err(Error::InvalidIdentifier { kind: "permissions", message, .. }) if !message.is_empty()));
+        assert!(matches!(Transaction::build(Transaction { stmts: vec!["SELEC * FROM person".to_string()], ..Transaction::default() }), Err(Error::Invalid { kind: "Transaction", .. })));
+        assert!(matches!(Relate::build(Relate {
+            from: expr::thing("person:tobie").unwrap(),
+            edge: "wrote",
+            with: expr::thing("article:surreal").unwrap(),
+            relation: Some(Table { name: "wrote", ..Table::default() }),
+            ..Relate::default()
+        }), Err(Error::InvalidRelation(_))));
+    }
+
+}
@@ -1,2 +1,12 @@
+// The tests keep the crate's `assert!(false, ..)` error arms and `len() > 0` checks.
+#![cfg_attr(test, allow(clippy::assertions_on_constants, clippy::len_zero))]
+pub mod error;
 pub mod db;
-pub mod builder;
\ No newline at end of file
+pub mod builder;
+pub mod migrate;
+pub use error::Error;
+#[cfg(feature = "derive")]
+pub use surrealqb_derive::SurrealTable;
+
+// The paths of `#[derive(SurrealTable)]` start with `::surrealqb`.
+extern crate self as surrealqb;
@@ -0,0 +1,322 @@
+use crate::builder::prelude::{ Kind, TableKind };
+use crate::builder::table::Table;
+use crate::builder::field::Field;
+use crate::builder::create::Create;
+use crate::builder::transaction::Transaction;
+use crate::builder::statement::Statement;
+use crate::builder::query::Query;
+use crate::builder::expr;
+use crate::db::Db;
+use crate::error::Error;
+use serde::{ Deserialize, Serialize };
+use sha2::{ Digest, Sha256 };
+use std::path::Path;
+
+/// The table which records the applied migrations.
+pub const TABLE: &str = "_surrealqb_migrations";
+
+/// # Migration
+///
+/// * `id` - The version of the migration, migrations are applied in the order of their ids.
+/// * `name` - The name of the migration.
+/// * `stmts` - The statements of the migration, e.g. the output of `Query::build` or the content of a `.surql` file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Migration {
+    pub id: u64,
+    pub name: String,
+    pub stmts: String,
+}
+
+impl Migration {
+    /// # A Migration of Statements
+    /// ## Parameters
+    /// * `id` - The version of the migration.
+    /// * `name` - The name of the migration.
+    /// * `stmts` - The SurrealQL statements.
+    pub fn new(id: u64, name: impl Into<String>, stmts: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            stmts: stmts.into(),
+        }
+    }
+
+    /// # A Migration of a Query
+    /// The query must build the same statements every time, or the checksum of the migration changes on the next run.
+    /// A `User` with a plaintext `password` is hashed with a random salt, set its `passhash` instead.
+    /// ## Parameters
+    /// * `id` - The version of the migration.
+    /// * `name` - The name of the migration.
+    /// * `query` - The `Query` struct, e.g. `Query::new(Schema::statements(schema)?)` or `Query::new(diff.changes)`.
+    pub fn query<T: Statement>(id: u64, name: impl Into<String>, mut query: Query<T>) -> Result<Self,Error> {
+        let name: String = name.into();
+        let stmts: String = query.build()?;
+        if query.build()? != stmts {
+            return Err(Error::invalid("Migration",format!(
+                "Migration {} {} builds different statements every time, e.g. a User with a password, set its passhash instead.",
+                id,name
+            )));
+        }
+        Ok(Migration::new(id, name, stmts))
+    }
+
+    /// # A Migration of a `.surql` File
+    /// The file is named after the id and the name of the migration, e.g. `0001_create_person.surql`.
+    /// ## Parameter
+    /// * `path` - The path of the file.
+    pub fn file(path: impl AsRef<Path>) -> Result<Self,Error> {
+        let path: &Path = path.as_ref();
+        let invalid = || Error::invalid("Migration",format!("Invalid migration file {}, expecting a file named <id>_<name>.surql.",path.display()));
+        if path.extension().and_then(|extension| extension.to_str()) != Some("surql") {
+            return Err(invalid());
+        }
+        let (id, name) = path.file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| stem.split_once('_'))
+            .ok_or_else(invalid)?;
+        let id: u64 = id.parse().map_err(|_| invalid())?;
+        let stmts: String = std::fs::read_to_string(path)
+            .map_err(|error| Error::invalid("Migration",format!("Cannot read {}: {}",path.display(),error)))?;
+        Ok(Migration::new(id, name, stmts))
+    }
+
+    /// # The Migrations of a Directory
+    /// Loads every `.surql` file of the directory, see: `Migration::file`.
+    /// ## Parameter
+    /// * `path` - The path of the directory.
+    pub fn dir(path: impl AsRef<Path>) -> Result<Vec<Self>,Error> {
+        let path: &Path = path.as_ref();
+        let entries = std::fs::read_dir(path)
+            .map_err(|error| Error::invalid("Migration",format!("Cannot read {}: {}",path.display(),error)))?;
+        let mut migrations: Vec<Migration> = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|error| Error::invalid("Migration",format!("Cannot read {}: {}",path.display(),error)))?;
+            if entry.path().extension().and_then(|extension| extension.to_str()) == Some("surql") {
+                migrations.push(Migration::file(entry.path())?);
+            }
+        }
+        migrations.sort_by_key(|migration| migration.id);
+        Ok(migrations)
+    }
+
+    /// # The Checksum of the Statements
+    /// The hex encoded SHA-256 of the statements, surrounding whitespace is ignored.
+    pub fn checksum(&self) -> String {
+        Sha256::digest(self.stmts.trim().as_bytes()).iter()
+            .map(|byte| format!("{:02x}",byte))
+            .collect()
+    }
+}
+
+/// # Applied
+/// A record of the `_surrealqb_migrations` table.
+///
+/// * `id` - The id of the migration, which is the id of the record.
+/// * `name` - The name of the migration.
+/// * `checksum` - The checksum of the statements when the migration was applied.
+/// * `applied_at` - When the migration was applied.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Applied {
+    #[serde(skip_serializing)]
+    pub id: u64,
+    pub name: String,
+    pub checksum: String,
+    #[serde(skip_serializing)]
+    pub applied_at: String,
+}
+
+/// # Migrator
+///
+/// * `migrations` - The registered migrations.
+#[derive(Debug, Clone, Default)]
+pub struct Migrator {
+    pub migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    /// # A Migrator of the Registered Migrations
+    /// ## Parameter
+    /// * `migrations` - The migrations, e.g. of `Migration::dir`.
+    pub fn new(migrations: Vec<Migration>) -> Self {
+        Self {
+            migrations,
+        }
+    }
+
+    /// # The Definition of the `_surrealqb_migrations` Table
+    /// Redefined on every run, `IF NOT EXISTS` fails on an existing table.
+    pub fn schema() -> Result<Vec<Box<dyn Statement>>,Error> {
+        let field = |name: &'static str, kind: Kind| Field {
+            name,
+            table: TABLE,
+            kind,
+            readonly: true,
+            ..Field::default()
+        };
+        Ok(vec![
+            Box::new(Table { name: TABLE, kind: TableKind::Normal, ..Table::default() }),
+            Box::new(field("name", Kind::String)),
+            Box::new(field("checksum", Kind::String)),
+            Box::new(Field { default: Some(expr::value("time::now()")?), ..field("applied_at", Kind::Datetime) }),
+        ])
+    }
+
+    /// # The Applied Migrations
+    /// ## Parameter
+    /// * `db` - The database.
+    pub async fn applied(db: &Db) -> Result<Vec<Applied>,Error> {
+        db.query(format!("SELECT meta::id(id) AS id, name, checksum, <string> applied_at AS applied_at FROM {} ORDER BY id;",TABLE)).await?
+            .take::<Vec<Applied>>(0)
+            .map_err(|error| Error::Execution(error.to_string()))
+    }
+
+    /// # The Pending Migrations
+    /// Fails when the registered migrations are invalid or an applied migration is not registered or its checksum changed.
+    /// ## Parameter
+    /// * `db` - The database.
+    pub async fn pending(&self, db: &Db) -> Result<Vec<&Migration>,Error> {
+        self.validate()?;
+        let applied: Vec<Applied> = Migrator::applied(db).await?;
+        for applied in applied.iter() {
+            match self.migrations.iter().find(|migration| migration.id == applied.id) {
+                None => return Err(Error::invalid("Migration",format!("Applied migration {} {} is not registered.",applied.id,applied.name))),
+                Some(migration) if migration.checksum() != applied.checksum => {
+                    return Err(Error::invalid("Migration",format!("The checksum of applied migration {} {} changed.",applied.id,applied.name)));
+                }
+                Some(_) => {}
+            }
+        }
+        let mut pending: Vec<&Migration> = self.migrations.iter()
+            .filter(|migration| !applied.iter().any(|applied| applied.id == migration.id))
+            .collect();
+        pending.sort_by_key(|migration| migration.id);
+        Ok(pending)
+    }
+
+    /// # Apply the Pending Migrations
+    /// Defines the `_surrealqb_migrations` table, then applies every pending migration in its own transaction together with its record.
+    /// Stops at the first failing migration, which is rolled back, the migrations before it stay applied.
+    /// ## Parameter
+    /// * `db` - The database.
+    pub async fn run(&self, db: &Db) -> Result<Vec<Applied>,Error> {
+        db.execute(&Query::new(Migrator::schema()?)).await?.check()?;
+        let mut applied: Vec<Applied> = Vec::new();
+        for migration in self.pending(db).await? {
+            let record: Applied = Applied {
+                id: migration.id,
+                name: migration.name.clone(),
+                checksum: migration.checksum(),
+                ..Applied::default()
+            };
+            let stmt: String = Transaction::build(Transaction {
+                stmts: vec![
+                    migration.stmts.clone(),
+                    Create::build(Create {
+                        what: vec![expr::thing(&format!("{}:{}",TABLE,migration.id))?],
+                        content: Some(expr::content(&record)?),
+                        ..Create::default()
+                    })?,
+                ],
+                ..Transaction::default()
+            }).map_err(|error| Error::invalid("Migration",format!("Migration {} {}: {}",migration.id,migration.name,error)))?;
+            let mut response = db.query(stmt).await?;
+            let mut errors: Vec<(usize, surrealdb::Error)> = response.take_errors().into_iter().collect();
+            errors.sort_by_key(|(index, _)| *index);
+            // The statements after the failing one are not executed.
+            if let Some((_, error)) = errors.iter()
+                .find(|(_, error)| !matches!(error, surrealdb::Error::Db(surrealdb::error::Db::QueryNotExecuted)))
+                .or(errors.first()) {
+                return Err(Error::Execution(format!("Migration {} {} failed: {}",migration.id,migration.name,error)));
+            }
+            applied.push(record);
+        }
+        Ok(applied)
+    }
+
+    /// The registered migrations have unique ids, names and statements.
+    fn validate(&self) -> Result<(),Error> {
+        for (index, migration) in self.migrations.iter().enumerate() {
+            if migration.name.is_empty() {
+                return Err(Error::MissingName { kind: "Migration", field: "name" });
+            }
+            if migration.stmts.trim().is_empty() {
+                return Err(Error::invalid("Migration",format!("Migration {} {} has no statements.",migration.id,migration.name)));
+            }
+            if self.migrations[..index].iter().any(|other| other.id == migration.id) {
+                return Err(Error::invalid("Migration",format!("Migration {} is registered more than once.",migration.id)));
+            }
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::user::{ User, UserLevel, UserRole };
+
+    #[tokio::test]
+    async fn test_migrate(){
+        let db = Db::memory("test","test").await.unwrap();
+        let mut migrations: Vec<Migration> = Vec::from([
+            Migration::query(1, "create_person", Query::new(vec![
+                Table { name: "person", kind: TableKind::Normal, ..Table::default() },
+            ])).unwrap(),
+            Migration::new(2, "add_person_name", "DEFINE FIELD name ON person TYPE string; CREATE person:tobie SET name = 'Tobie';"),
+        ]);
+        let applied = Migrator::new(migrations.clone()).run(&db).await.unwrap();
+        assert_eq!(applied.iter().map(|applied| applied.id).collect::<Vec<u64>>(),vec![1,2]);
+        let recorded: Vec<Applied> = Migrator::applied(&db).await.unwrap();
+        assert_eq!(recorded.len(),2);
+        assert_eq!(recorded[1].checksum,migrations[1].checksum());
+        assert!(!recorded[1].applied_at.is_empty());
+        // Only pending migrations are applied.
+        assert!(Migrator::new(migrations.clone()).run(&db).await.unwrap().is_empty());
+        migrations.push(Migration::new(3, "add_person_email", "DEFINE FIELD email ON person TYPE option<string>;"));
+        assert_eq!(Migrator::new(migrations.clone()).run(&db).await.unwrap().len(),1);
+
+        // Expecting Error
+        let mut failing: Vec<Migration> = migrations.clone();
+        failing.push(Migration::new(4, "duplicate_tobie", "CREATE person:jaime SET name = 'Jaime'; CREATE person:tobie SET name = 'Tobie';"));
+        assert!(matches!(Migrator::new(failing).run(&db).await, Err(Error::Execution(_))));
+        assert_eq!(Migrator::applied(&db).await.unwrap().len(),3);
+        let mut response = db.query("SELECT * FROM person:jaime;").await.unwrap();
+        assert!(response.take::<Vec<serde_json::Value>>(0).unwrap().is_empty());
+        let mut changed: Vec<Migration> = migrations.clone();
+        changed[1].stmts = "DEFINE FIELD name ON person TYPE option<string>;".to_string();
+        assert!(matches!(Migrator::new(changed).run(&db).await, Err(Error::Invalid { kind: "Migration", .. })));
+        assert!(matches!(Migrator::new(migrations[..2].to_vec()).run(&db).await, Err(Error::Invalid { kind: "Migration", .. })));
+        let mut duplicate: Vec<Migration> = migrations.clone();
+        duplicate.push(Migration::new(3, "again", "DEFINE TABLE again;"));
+        assert!(matches!(Migrator::new(duplicate).run(&db).await, Err(Error::Invalid { kind: "Migration", .. })));
+
+        // Migrations of a directory
+        let dir = std::env::temp_dir().join(format!("surrealqb_migrate_{}",std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("0002_add_post_title.surql"),"DEFINE FIELD title ON post TYPE string;").unwrap();
+        std::fs::write(dir.join("0001_create_post.surql"),"DEFINE TABLE post SCHEMAFULL;").unwrap();
+        std::fs::write(dir.join("README.md"),"Not a migration.").unwrap();
+        let loaded: Result<Vec<Migration>,Error> = Migration::dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let loaded: Vec<Migration> = loaded.unwrap();
+        assert_eq!(loaded.iter().map(|migration| (migration.id, migration.name.as_str())).collect::<Vec<(u64,&str)>>(),vec![(1,"create_post"),(2,"add_post_title")]);
+        let other = Db::memory("test","other").await.unwrap();
+        assert_eq!(Migrator::new(loaded).run(&other).await.unwrap().len(),2);
+        assert!(Migration::file("create_post.surql").is_err());
+
+        // Migrations of a query are built the same every time.
+        let users = |password: &'static str, passhash: &'static str| Query::new(vec![
+            User { name: "admin", level: UserLevel::Database, password, passhash, roles: vec![UserRole::Owner], ..User::default() },
+        ]);
+        let passhash: &str = "$argon2id$v=19$m=19456,t=2,p=1$c2FsdHNhbHQ$aGFzaGhhc2g";
+        let migration: Migration = Migration::query(1, "define_admin", users("", passhash)).unwrap();
+        assert_eq!(Migration::query(1, "define_admin", users("", passhash)).unwrap(),migration);
+        let users_db = Db::memory("test","users").await.unwrap();
+        assert_eq!(Migrator::new(vec![migration.clone()]).run(&users_db).await.unwrap().len(),1);
+        let migration: Migration = Migration::query(1, "define_admin", users("", passhash)).unwrap();
+        assert!(Migrator::new(vec![migration]).run(&users_db).await.unwrap().is_empty());
+        assert!(matches!(Migration::query(1, "define_admin", users("secret", "")), Err(Error::Invalid { kind: "Migration", .. })));
+    }
+
+}
@@ -0,0 +1,436 @@
+//! Derive macro of `surrealqb`.
+//!
+//! `#[derive(SurrealTable)]` implements `surrealqb::builder::derive::SurrealTable` for a struct with named fields.
+//! See the documentation of the trait for the supported attributes.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{ parse_macro_input, Data, DeriveInput, Fields, GenericArgument, LitStr, PathArguments, Type };
+
+/// The `#[surreal(..)]` attributes of the struct.
+#[derive(Default)]
+struct TableAttrs {
+    name: Option<String>,
+    schemaless: bool,
+    comment: Option<String>,
+    permissions: Option<String>,
+    /// The `#[serde(rename_all = "..")]` of the struct.
+    rename_all: Option<String>,
+}
+
+/// The `#[surreal(..)]` attributes of a field.
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    kind: Option<String>,
+    record: Vec<String>,
+    assert: Option<String>,
+    default: Option<String>,
+    value: Option<String>,
+    readonly: bool,
+    flexible: bool,
+    permissions: Option<String>,
+    comment: Option<String>,
+    /// The index name and whether it is unique.
+    index: Option<(Option<String>, bool)>,
+    /// The `#[serde(rename = "..")]` of the field.
+    serde_rename: Option<String>,
+    /// Whether the field has `#[serde(with = "..")]` or `#[serde(serialize_with = "..")]`.
+    serde_with: bool,
+}
+
+#[proc_macro_derive(SurrealTable, attributes(surreal))]
+pub fn derive_surreal_table(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(&input.ident, "SurrealTable requires a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(&input.ident, "SurrealTable requires a struct with named fields")),
+    };
+    let attrs: TableAttrs = table_attrs(&input)?;
+    let table: String = attrs.name.unwrap_or_else(|| snake_case(&input.ident.to_string()));
+    let schema_less: bool = attrs.schemaless;
+    let table_comment: String = attrs.comment.unwrap_or_default();
+    let table_permissions: TokenStream2 = permissions(&attrs.permissions);
+    let attrs_rename_all: Option<String> = attrs.rename_all;
+
+    let mut defines: Vec<TokenStream2> = Vec::new();
+    // The indexes in order of their first field: name, unique, columns.
+    let mut indexes: Vec<(String, bool, Vec<String>)> = Vec::new();
+    for field in fields {
+        let attrs: FieldAttrs = field_attrs(field)?;
+        if attrs.skip {
+            continue;
+        }
+        // The field name of the serialized record.
+        let name: String = match (&attrs.rename, &attrs.serde_rename) {
+            (Some(rename), Some(serde_rename)) if rename != serde_rename => {
+                return Err(syn::Error::new_spanned(field, format!("surreal rename {} does not match serde rename {}", rename, serde_rename)));
+            },
+            (Some(rename), _) | (None, Some(rename)) => rename.clone(),
+            (None, None) => {
+                let name: String = field.ident.as_ref().map(|ident| ident.to_string().trim_start_matches("r#").to_string()).unwrap_or_default();
+                rename_all(&name, &attrs_rename_all)?
+            },
+        };
+        if let Some((index, unique)) = &attrs.index {
+            let index: String = index.clone().unwrap_or_else(|| format!("{}_idx", name));
+            match indexes.iter_mut().find(|(existing, _, _)| *existing == index) {
+                Some((_, existing, cols)) => {
+                    if existing != unique {
+                        return Err(syn::Error::new_spanned(field, format!("index {} cannot be both unique and not unique", index)));
+                    }
+                    cols.push(name.clone());
+                },
+                None => indexes.push((index, *unique, vec![name.clone()])),
+            }
+        }
+        // The id of a record is defined by SurrealDB.
+        if name == "id" {
+            continue;
+        }
+        let kind: TokenStream2 = match &attrs.kind {
+            Some(kind) => quote!( ::surrealqb::builder::expr::kind(#kind)? ),
+            None => {
+                // chrono serializes its dates as strings, which a datetime field rejects.
+                if chrono(&field.ty) && !attrs.serde_with {
+                    return Err(syn::Error::new_spanned(&field.ty, "chrono serializes dates as strings, set #[serde(with = \"surrealqb::builder::derive::datetime\")]"));
+                }
+                kind(&field.ty, &attrs.record)?
+            },
+        };
+        let optional = |value: &Option<String>| match value {
+            Some(value) => quote!( Some(::surrealqb::builder::expr::value(#value)?) ),
+            None => quote!( None ),
+        };
+        let assert: TokenStream2 = optional(&attrs.assert);
+        let default: TokenStream2 = optional(&attrs.default);
+        let value: TokenStream2 = optional(&attrs.value);
+        let readonly: bool = attrs.readonly;
+        let flexible: bool = attrs.flexible;
+        let comment: String = attrs.comment.unwrap_or_default();
+        let permissions: TokenStream2 = permissions(&attrs.permissions);
+        defines.push(quote! {
+            ::surrealqb::builder::field::Field {
+                name: #name,
+                table: #table,
+                kind: #kind,
+                flexible: #flexible,
+                readonly: #readonly,
+                default: #default,
+                assert: #assert,
+                value: #value,
+                permissions: #permissions,
+                comment: #comment,
+                ..::core::default::Default::default()
+            }
+        });
+    }
+    let indexes: Vec<TokenStream2> = indexes.into_iter().map(|(name, unique, cols)| {
+        let index: TokenStream2 = match unique {
+            true => quote!( ::surrealqb::builder::prelude::IndexType::Uniq ),
+            false => quote!( ::surrealqb::builder::prelude::IndexType::Idx ),
+        };
+        quote! {
+            ::surrealqb::builder::index::Index {
+                name: #name,
+                table: #table,
+                cols: vec![#( ::surrealqb::builder::prelude::Idiom::from(#cols) ),*],
+                index: #index,
+                ..::core::default::Default::default()
+            }
+        }
+    }).collect();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::surrealqb::builder::derive::SurrealTable for #ident #ty_generics #where_clause {
+            fn table() -> ::core::result::Result<::surrealqb::builder::table::Table<'static>, ::surrealqb::Error> {
+                Ok(::surrealqb::builder::table::Table {
+                    name: #table,
+                    kind: ::surrealqb::builder::prelude::TableKind::Normal,
+                    schema_less: #schema_less,
+                    permissions: #table_permissions,
+                    comment: #table_comment,
+                    ..::core::default::Default::default()
+                })
+            }
+
+            fn fields() -> ::core::result::Result<::std::vec::Vec<::surrealqb::builder::field::Field<'static>>, ::surrealqb::Error> {
+                Ok(vec![#( #defines ),*])
+            }
+
+            fn indexes() -> ::core::result::Result<::std::vec::Vec<::surrealqb::builder::index::Index<'static>>, ::surrealqb::Error> {
+                Ok(vec![#( #indexes ),*])
+            }
+        }
+    })
+}
+
+fn table_attrs(input: &DeriveInput) -> syn::Result<TableAttrs> {
+    let mut attrs: TableAttrs = TableAttrs::default();
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                attrs.rename_all = serde_name(&meta)?;
+                return Ok(());
+            }
+            skip_meta(&meta)
+        })?;
+    }
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("surreal")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table") {
+                attrs.name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("schemaless") {
+                attrs.schemaless = true;
+            } else if meta.path.is_ident("comment") {
+                attrs.comment = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("permissions") {
+                attrs.permissions = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else {
+                return Err(meta.error("unsupported surreal attribute, expected table, schemaless, comment or permissions"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attrs)
+}
+
+fn field_attrs(field: &syn::Field) -> syn::Result<FieldAttrs> {
+    let mut attrs: FieldAttrs = FieldAttrs::default();
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                attrs.serde_rename = serde_name(&meta)?;
+            } else if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                attrs.skip = true;
+            } else if meta.path.is_ident("with") || meta.path.is_ident("serialize_with") {
+                attrs.serde_with = true;
+                skip_meta(&meta)?;
+            } else {
+                skip_meta(&meta)?;
+            }
+            Ok(())
+        })?;
+    }
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("surreal")) {
+        attr.parse_nested_meta(|meta| {
+            let string = |meta: &syn::meta::ParseNestedMeta| -> syn::Result<String> {
+                Ok(meta.value()?.parse::<LitStr>()?.value())
+            };
+            if meta.path.is_ident("rename") {
+                attrs.rename = Some(string(&meta)?);
+            } else if meta.path.is_ident("skip") {
+                attrs.skip = true;
+            } else if meta.path.is_ident("kind") {
+                attrs.kind = Some(string(&meta)?);
+            } else if meta.path.is_ident("record") {
+                attrs.record.push(string(&meta)?);
+            } else if meta.path.is_ident("assert") {
+                attrs.assert = Some(string(&meta)?);
+            } else if meta.path.is_ident("default") {
+                attrs.default = Some(string(&meta)?);
+            } else if meta.path.is_ident("value") {
+                attrs.value = Some(string(&meta)?);
+            } else if meta.path.is_ident("readonly") {
+                attrs.readonly = true;
+            } else if meta.path.is_ident("flexible") {
+                attrs.flexible = true;
+            } else if meta.path.is_ident("permissions") {
+                attrs.permissions = Some(string(&meta)?);
+            } else if meta.path.is_ident("comment") {
+                attrs.comment = Some(string(&meta)?);
+            } else if meta.path.is_ident("index") || meta.path.is_ident("unique") {
+                let unique: bool = meta.path.is_ident("unique");
+                let name: Option<String> = match meta.input.peek(syn::Token![=]) {
+                    true => Some(string(&meta)?),
+                    false => None,
+                };
+                attrs.index = Some((name, unique));
+            } else {
+                return Err(meta.error("unsupported surreal attribute, expected rename, skip, kind, record, assert, default, value, readonly, flexible, permissions, comment, index or unique"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(attrs)
+}
+
+/// The serialized name of `rename = ".."` or `rename(serialize = "..")`.
+fn serde_name(meta: &syn::meta::ParseNestedMeta) -> syn::Result<Option<String>> {
+    if meta.input.peek(syn::Token![=]) {
+        return Ok(Some(meta.value()?.parse::<LitStr>()?.value()));
+    }
+    let mut name: Option<String> = None;
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("serialize") {
+            name = Some(meta.value()?.parse::<LitStr>()?.value());
+            return Ok(());
+        }
+        skip_meta(&meta)
+    })?;
+    Ok(name)
+}
+
+/// Skip a serde attribute which does not change the schema.
+fn skip_meta(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))?;
+    }
+    Ok(())
+}
+
+/// Rename a field as `#[serde(rename_all = "..")]` does.
+fn rename_all(name: &str, rename_all: &Option<String>) -> syn::Result<String> {
+    let pascal = || -> String {
+        name.split('_').map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        }).collect()
+    };
+    Ok(match rename_all.as_deref() {
+        None | Some("lowercase") | Some("snake_case") => name.to_string(),
+        Some("UPPERCASE") | Some("SCREAMING_SNAKE_CASE") => name.to_ascii_uppercase(),
+        Some("PascalCase") => pascal(),
+        Some("camelCase") => {
+            let pascal: String = pascal();
+            let mut chars = pascal.chars();
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        },
+        Some("kebab-case") => name.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => name.to_ascii_uppercase().replace('_', "-"),
+        Some(rule) => return Err(syn::Error::new(proc_macro2::Span::call_site(), format!("unsupported serde rename_all {}", rule))),
+    })
+}
+
+fn permissions(permissions: &Option<String>) -> TokenStream2 {
+    match permissions {
+        Some(permissions) => quote!( ::surrealqb::builder::expr::permissions(#permissions)? ),
+        None => quote!( ::surrealqb::builder::prelude::Permissions::default() ),
+    }
+}
+
+/// Map a Rust type to a `Kind`, unknown types need `#[surreal(kind = "..")]`.
+/// The `record` tables only apply to the record ids.
+fn kind(ty: &Type, record: &[String]) -> syn::Result<TokenStream2> {
+    let kind_path: TokenStream2 = quote!( ::surrealqb::builder::prelude::Kind );
+    match ty {
+        Type::Reference(reference) => kind(&reference.elem, record),
+        Type::Paren(paren) => kind(&paren.elem, record),
+        Type::Group(group) => kind(&group.elem, record),
+        Type::Array(array) => {
+            let inner: TokenStream2 = kind(&array.elem, record)?;
+            Ok(quote!( #kind_path::Array(Box::new(#inner), None) ))
+        },
+        Type::Slice(slice) => {
+            let inner: TokenStream2 = kind(&slice.elem, record)?;
+            Ok(quote!( #kind_path::Array(Box::new(#inner), None) ))
+        },
+        Type::Path(path) => {
+            let segment = match path.path.segments.last() {
+                Some(segment) => segment,
+                None => return Err(syn::Error::new_spanned(ty, "unsupported type, set #[surreal(kind = \"..\")]")),
+            };
+            let args: Vec<&Type> = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().filter_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                }).collect(),
+                _ => Vec::new(),
+            };
+            let inner = || -> syn::Result<TokenStream2> {
+                match args.first() {
+                    Some(ty) => kind(ty, record),
+                    None => Err(syn::Error::new_spanned(ty, "missing type argument, set #[surreal(kind = \"..\")]")),
+                }
+            };
+            Ok(match segment.ident.to_string().as_str() {
+                "String" | "str" | "char" | "Strand" => quote!( #kind_path::String ),
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize"
+                | "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => quote!( #kind_path::Int ),
+                "f32" | "f64" => quote!( #kind_path::Float ),
+                "bool" => quote!( #kind_path::Bool ),
+                "Number" => quote!( #kind_path::Number ),
+                "Datetime" | "DateTime" | "NaiveDateTime" | "NaiveDate" => quote!( #kind_path::Datetime ),
+                "Bytes" => quote!( #kind_path::Bytes ),
+                "Value" => quote!( #kind_path::Any ),
+                "Thing" => match record.is_empty() {
+                    true => quote!( #kind_path::Record(vec![]) ),
+                    false => {
+                        let record: String = format!("record<{}>", record.join(" | "));
+                        quote!( ::surrealqb::builder::expr::kind(#record)? )
+                    }
+                },
+                "Box" | "Rc" | "Arc" | "Cow" => inner()?,
+                "Option" => {
+                    let inner: TokenStream2 = inner()?;
+                    quote!( #kind_path::Option(Box::new(#inner)) )
+                },
+                "Vec" | "VecDeque" | "LinkedList" => {
+                    let inner: TokenStream2 = inner()?;
+                    quote!( #kind_path::Array(Box::new(#inner), None) )
+                },
+                "HashSet" | "BTreeSet" => {
+                    let inner: TokenStream2 = inner()?;
+                    quote!( #kind_path::Set(Box::new(#inner), None) )
+                },
+                "HashMap" | "BTreeMap" => quote!( #kind_path::Object ),
+                name => return Err(syn::Error::new_spanned(ty, format!("unsupported type {}, set #[surreal(kind = \"..\")]", name))),
+            })
+        },
+        _ => Err(syn::Error::new_spanned(ty, "unsupported type, set #[surreal(kind = \"..\")]")),
+    }
+}
+
+/// Whether the type is or contains a chrono date.
+fn chrono(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => chrono(&reference.elem),
+        Type::Paren(paren) => chrono(&paren.elem),
+        Type::Group(group) => chrono(&group.elem),
+        Type::Array(array) => chrono(&array.elem),
+        Type::Slice(slice) => chrono(&slice.elem),
+        Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+            matches!(segment.ident.to_string().as_str(), "DateTime" | "NaiveDateTime" | "NaiveDate") || match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| matches!(arg, GenericArgument::Type(ty) if chrono(ty))),
+                _ => false,
+            }
+        }),
+        _ => false,
+    }
+}
+
+fn snake_case(name: &str) -> String {
+    let mut snake: String = String::new();
+    for (index, char) in name.char_indices() {
+        if char.is_uppercase() {
+            if index > 0 {
+                snake.push('_');
+            }
+            snake.extend(char.to_lowercase());
+        } else {
+            snake.push(char);
+        }
+    }
+    snake
+}